@@ -1,5 +1,5 @@
-use super::{Authorization, AuthorizationKind, Osu, OsuRef, Token};
-use crate::{error::OsuError, OsuResult};
+use super::{Authorization, AuthorizationKind, Osu, OsuRef, Token, API_VERSION};
+use crate::{error::OsuError, request::RankingsCache, OsuResult};
 
 use hyper::client::Builder;
 use hyper_rustls::HttpsConnectorBuilder;
@@ -25,6 +25,8 @@ pub struct OsuBuilder {
     retries: usize,
     timeout: Duration,
     per_second: u32,
+    api_version: u32,
+    rankings_cache: RankingsCache,
 }
 
 impl Default for OsuBuilder {
@@ -37,6 +39,8 @@ impl Default for OsuBuilder {
             retries: 2,
             timeout: Duration::from_secs(10),
             per_second: 15,
+            api_version: API_VERSION,
+            rankings_cache: RankingsCache::default(),
         }
     }
 }
@@ -91,6 +95,8 @@ impl OsuBuilder {
             auth_kind: self.auth_kind.unwrap_or_default(),
             token: RwLock::new(Token::default()),
             retries: self.retries,
+            api_version: self.api_version,
+            rankings_cache: self.rankings_cache,
         });
 
         // Acquire the initial API token
@@ -173,13 +179,30 @@ impl OsuBuilder {
         self
     }
 
+    /// Set the value of the `x-api-version` header which determines
+    /// the format of some responses, defaults to `20220705`.
+    #[inline]
+    pub fn api_version(mut self, api_version: u32) -> Self {
+        self.api_version = api_version;
+
+        self
+    }
+
+    /// Set the cache that [`Osu::estimate_rank`] and [`Osu::pp_for_rank`] use for
+    /// leaderboard pages, defaults to a cache whose pages expire after ten minutes.
+    #[inline]
+    pub fn rankings_cache(mut self, cache: RankingsCache) -> Self {
+        self.rankings_cache = cache;
+
+        self
+    }
+
     /// Set the amount of requests that can be made in one second, defaults to 15.
     /// The given value will be clamped between 1 and 20.
     ///
     /// Check out the osu!api's [terms of use] for acceptable values.
     ///
     /// [terms of use]: https://osu.ppy.sh/docs/index.html#terms-of-use
-
     #[inline]
     pub fn ratelimit(mut self, reqs_per_sec: u32) -> Self {
         self.per_second = reqs_per_sec.clamp(1, 20);
@@ -7,7 +7,12 @@ use token::{Authorization, AuthorizationKind, Token, TokenResponse};
 pub use builder::OsuBuilder;
 pub use token::Scope;
 
-use crate::{error::OsuError, model::GameMode, request::*, OsuResult};
+use crate::{
+    error::OsuError,
+    model::{comments::CommentableType, GameMode},
+    request::*,
+    OsuResult,
+};
 
 use hyper::{
     body::{Body as HyperBody, HttpBody, SizeHint},
@@ -196,6 +201,32 @@ impl Osu {
         GetBeatmapsetFromMapId::new(self, map_id)
     }
 
+    /// Add a beatmapset to the authenticated user's favourites.
+    ///
+    /// The result is the mapset's updated favourite count.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn favourite_beatmapset(&self, mapset_id: u32) -> FavouriteBeatmapset<'_> {
+        FavouriteBeatmapset::new(self, mapset_id, true)
+    }
+
+    /// Remove a beatmapset from the authenticated user's favourites.
+    ///
+    /// The result is the mapset's updated favourite count.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn unfavourite_beatmapset(&self, mapset_id: u32) -> FavouriteBeatmapset<'_> {
+        FavouriteBeatmapset::new(self, mapset_id, false)
+    }
+
     /// Get a [`BeatmapsetEvents`](crate::model::beatmap::BeatmapsetEvents)
     /// struct containing the most recent mapset events.
     #[inline]
@@ -237,6 +268,32 @@ impl Osu {
         GetBeatmapsetSearch::new(self)
     }
 
+    /// Get a [`Changelog`](crate::model::changelog::Changelog) struct containing
+    /// the most recent builds and all update streams.
+    ///
+    /// The contained [`ChangelogBuild`](crate::model::changelog::ChangelogBuild)s
+    /// will have the `changelog_entries` and `update_stream` options filled.
+    ///
+    /// Each [`UpdateStream`](crate::model::changelog::UpdateStream) of the
+    /// stream list will have the `latest_build` and `user_count` options filled.
+    #[inline]
+    pub fn changelog(&self) -> GetChangelog<'_> {
+        GetChangelog::new(self)
+    }
+
+    /// Get a specific [`ChangelogBuild`](crate::model::changelog::ChangelogBuild)
+    /// of an update stream, e.g. `osu.changelog_build("lazer", "2023.1008.1")`.
+    ///
+    /// Filled options will be: `changelog_entries`, `update_stream`, and `versions`.
+    #[inline]
+    pub fn changelog_build(
+        &self,
+        stream: impl Into<String>,
+        version: impl Into<String>,
+    ) -> GetChangelogBuild<'_> {
+        GetChangelogBuild::new(self, stream, version)
+    }
+
     /// Get a list of comments and their replies up to two levels deep
     /// in form of a [`CommentBundle`](crate::model::comments::CommentBundle) .
     #[inline]
@@ -244,6 +301,67 @@ impl Osu {
         GetComments::new(self)
     }
 
+    /// Post a comment on the given object, or a reply to a comment
+    /// by specifying [`PostComment::parent`].
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn post_comment(
+        &self,
+        commentable_type: CommentableType,
+        commentable_id: u32,
+        message: impl Into<String>,
+    ) -> PostComment<'_> {
+        PostComment::new(self, commentable_type, commentable_id, message.into())
+    }
+
+    /// Replace the message of one of the authenticated user's comments.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn edit_comment(&self, comment_id: u32, message: impl Into<String>) -> EditComment<'_> {
+        EditComment::new(self, comment_id, message.into())
+    }
+
+    /// Delete one of the authenticated user's comments.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn delete_comment(&self, comment_id: u32) -> DeleteComment<'_> {
+        DeleteComment::new(self, comment_id)
+    }
+
+    /// Upvote a comment.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn vote_comment(&self, comment_id: u32) -> VoteComment<'_> {
+        VoteComment::new(self, comment_id, true)
+    }
+
+    /// Remove the authenticated user's upvote from a comment.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn unvote_comment(&self, comment_id: u32) -> VoteComment<'_> {
+        VoteComment::new(self, comment_id, false)
+    }
+
     /// Get a [`ChartRankings`](crate::model::ranking::ChartRankings) struct
     /// containing a [`Spotlight`](crate::model::ranking::Spotlight), its
     /// [`Beatmapset`](crate::model::beatmap::Beatmapset)s, and participating
@@ -268,6 +386,43 @@ impl Osu {
         GetCountryRankings::new(self, mode)
     }
 
+    /// Get the most recent page of the global events feed in form of
+    /// [`Events`](crate::model::recent_event::Events).
+    ///
+    /// To only request the events of a single user, use [`Osu::recent_events`].
+    #[inline]
+    pub fn events(&self) -> GetEvents<'_> {
+        GetEvents::new(self)
+    }
+
+    /// Get an [`EventStream`] that continuously polls the global events feed
+    /// and yields each new [`RecentEvent`](crate::model::recent_event::RecentEvent)
+    /// exactly once, oldest first.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use rosu_v2::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # let _ = async {
+    /// # let osu: Osu = unimplemented!();
+    /// let mut stream = osu.event_stream().interval(Duration::from_secs(30));
+    ///
+    /// while let Some(event) = stream.next().await {
+    ///     if let EventType::Medal { medal, user } = event?.event_type {
+    ///         println!("{} unlocked {}", user.username, medal.name);
+    ///     }
+    /// }
+    /// # Ok::<_, OsuError>(())
+    /// # };
+    /// ```
+    #[inline]
+    pub fn event_stream(&self) -> EventStream<'_> {
+        EventStream::new(self)
+    }
+
     /// Get a [`ForumPosts`](crate::model::forum::ForumPosts) struct for a forum topic
     #[inline]
     pub fn forum_posts(&self, topic_id: u64) -> GetForumPosts<'_> {
@@ -296,12 +451,88 @@ impl Osu {
         GetNews::new(self)
     }
 
+    /// Get a single [`NewsPost`](crate::model::news::NewsPost) by its slug,
+    /// i.e. the filename without extension that is used in URLs.
+    ///
+    /// Filled options will be: `content`, `navigation`, and `updated_at`.
+    #[inline]
+    pub fn news_post(&self, slug: impl Into<String>) -> GetNewsPost<'_> {
+        GetNewsPost::new(self, slug)
+    }
+
+    /// Get a single [`NewsPost`](crate::model::news::NewsPost) by its id.
+    ///
+    /// Filled options will be: `content`, `navigation`, and `updated_at`.
+    #[inline]
+    pub fn news_post_by_id(&self, post_id: u32) -> GetNewsPost<'_> {
+        GetNewsPost::with_id(self, post_id)
+    }
+
+    /// Get the authenticated user's most recent
+    /// [`Notifications`](crate::model::notification::Notifications).
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn notifications(&self) -> GetNotifications<'_> {
+        GetNotifications::new(self)
+    }
+
+    /// Mark notifications of the authenticated user as read.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn mark_notifications_read(&self) -> MarkNotificationsRead<'_> {
+        MarkNotificationsRead::new(self)
+    }
+
     /// Get an [`OsuMatch`](crate::model::matches::OsuMatch).
     #[inline]
     pub fn osu_match(&self, match_id: u32) -> GetMatch<'_> {
         GetMatch::new(self, match_id)
     }
 
+    /// Get an [`OsuMatch`](crate::model::matches::OsuMatch) including all of its events.
+    ///
+    /// The API only sends up to 100 events per request so this pages backwards
+    /// until the first event and merges all pages into a single match.
+    #[inline]
+    pub fn osu_match_full(&self, match_id: u32) -> GetMatchFull<'_> {
+        GetMatchFull::new(self, match_id)
+    }
+
+    /// Get a [`MatchStream`] which continuously polls a multiplayer match and
+    /// yields new events as well as updates of games in progress.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use rosu_v2::{model::matches::MatchUpdate, prelude::*};
+    ///
+    /// # let _ = async {
+    /// # let osu: Osu = unimplemented!();
+    /// let mut stream = osu.osu_match_stream(123_456_789);
+    ///
+    /// while let Some(update) = stream.next().await {
+    ///     match update? {
+    ///         MatchUpdate::Event(event) => println!("New event {}", event.event_id()),
+    ///         MatchUpdate::Game { game, .. } => println!("{} scores so far", game.scores.len()),
+    ///     }
+    /// }
+    /// # Ok::<_, OsuError>(())
+    /// # };
+    /// ```
+    #[inline]
+    pub fn osu_match_stream(&self, match_id: u32) -> MatchStream<'_> {
+        MatchStream::new(self, match_id)
+    }
+
     /// Get a [`MatchList`](crate::model::matches::MatchList) containing all
     /// currently open multiplayer lobbies.
     #[inline]
@@ -320,6 +551,17 @@ impl Osu {
         GetOwnData::new(self)
     }
 
+    /// Get the beatmapset ids of all favourite beatmapsets of the authenticated user.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn own_beatmapset_favourites(&self) -> GetOwnBeatmapsetFavourites<'_> {
+        GetOwnBeatmapsetFavourites::new(self)
+    }
+
     /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
     /// [`UserCompact`](crate::model::user::UserCompact)s are sorted
     /// by their pp, i.e. the current pp leaderboard.
@@ -328,6 +570,24 @@ impl Osu {
         GetPerformanceRankings::new(self, mode)
     }
 
+    /// Estimate the rank that the given amount of pp would have on the performance leaderboard.
+    ///
+    /// The leaderboard pages are binary searched so only a few requests are necessary.
+    /// Requested pages are kept in the client's [`RankingsCache`].
+    #[inline]
+    pub fn estimate_rank(&self, mode: GameMode, pp: f32) -> GetRankEstimate<'_> {
+        GetRankEstimate::new(self, mode, pp)
+    }
+
+    /// Get the pp of the user at the given rank of the performance leaderboard,
+    /// i.e. the amount of pp that must be exceeded in order to reach that rank.
+    ///
+    /// Requested pages are kept in the client's [`RankingsCache`].
+    #[inline]
+    pub fn pp_for_rank(&self, mode: GameMode, rank: u32) -> GetPpForRank<'_> {
+        GetPpForRank::new(self, mode, rank)
+    }
+
     /// Get the recent activity of a user in form of a vec of
     /// [`RecentEvent`](crate::model::recent_event::RecentEvent)s.
     #[cfg(not(feature = "cache"))]
@@ -346,25 +606,33 @@ impl Osu {
 
     /// Get the replay of a score in form of a [`Replay`](osu_db::Replay).
     ///
+    /// The score id is considered to be an osu!lazer score id unless
+    /// a mode is specified through [`GetReplay::mode`] in which case it
+    /// is considered to be a legacy score id of osu!stable for that mode.
+    ///
     /// Note that the client has to be initialized through the OAuth process
     /// in order for this endpoint to not return an error.
     ///
     /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
     #[cfg(feature = "replay")]
     #[inline]
-    pub fn replay(&self, mode: GameMode, score_id: u64) -> GetReplay<'_> {
-        GetReplay::new(self, mode, score_id)
+    pub fn replay(&self, score_id: u64) -> GetReplay<'_> {
+        GetReplay::new(self, score_id)
     }
 
     /// Get the bytes of a replay of a score in form of a `Vec<u8>`.
     ///
+    /// The score id is considered to be an osu!lazer score id unless
+    /// a mode is specified through [`GetReplayRaw::mode`] in which case it
+    /// is considered to be a legacy score id of osu!stable for that mode.
+    ///
     /// Note that the client has to be initialized through the OAuth process
     /// in order for this endpoint to not return an error.
     ///
     /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
     #[inline]
-    pub fn replay_raw(&self, mode: GameMode, score_id: u64) -> GetReplayRaw<'_> {
-        GetReplayRaw::new(self, mode, score_id)
+    pub fn replay_raw(&self, score_id: u64) -> GetReplayRaw<'_> {
+        GetReplayRaw::new(self, score_id)
     }
 
     /// Get a [`Score`](crate::model::score::Score) struct.
@@ -374,9 +642,13 @@ impl Osu {
     /// (will contain `artist_unicode` and `title_unicode`), `pp`
     /// (if ranked), `rank_global` (if on leaderboard map) and `user`
     /// (will contain `last_visited`, `country`, `cover` and `groups`)
+    ///
+    /// The score id is considered to be an osu!lazer score id unless
+    /// a mode is specified through [`GetScore::mode`] in which case it
+    /// is considered to be a legacy score id of osu!stable for that mode.
     #[inline]
-    pub fn score(&self, score_id: u64, mode: GameMode) -> GetScore<'_> {
-        GetScore::new(self, score_id, mode)
+    pub fn score(&self, score_id: u64) -> GetScore<'_> {
+        GetScore::new(self, score_id)
     }
 
     /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
@@ -387,6 +659,19 @@ impl Osu {
         GetScoreRankings::new(self, mode)
     }
 
+    /// Search for users and wiki pages whose name or content matches the query
+    /// in form of a [`SearchResult`](crate::model::search::SearchResult).
+    ///
+    /// Unlike [`Osu::user`], the query does not need to be an exact username
+    /// which makes this endpoint useful to resolve partial or misspelled names.
+    ///
+    /// The contained [`UserCompact`](crate::model::user::UserCompact)s
+    /// won't have any options filled.
+    #[inline]
+    pub fn search(&self, query: impl Into<String>) -> GetSearch<'_> {
+        GetSearch::new(self, query)
+    }
+
     /// Get [`SeasonalBackgrounds`](crate::model::seasonal_backgrounds::SeasonalBackgrounds).
     #[inline]
     pub fn seasonal_backgrounds(&self) -> GetSeasonalBackgrounds<'_> {
@@ -494,10 +779,10 @@ impl Osu {
     ///
     /// - The API provides at most 100 results per requests and defaults to 5.
     /// - For the `recent` score type, failed score are excluded by default.
-    /// Use [`include_fails`](crate::request::GetUserScores::include_fails)
-    /// to include them.
+    ///   Use [`include_fails`](crate::request::GetUserScores::include_fails)
+    ///   to include them.
     /// - For the `firsts` score type, `pp` will only be `Some` if the map
-    /// is not loved.
+    ///   is not loved.
     #[cfg(not(feature = "cache"))]
     #[inline]
     pub fn user_scores(&self, user_id: u32) -> GetUserScores<'_> {
@@ -525,10 +810,10 @@ impl Osu {
     ///
     /// - The API provides at most 100 results per requests and defaults to 5.
     /// - For the `recent` score type, failed score are excluded by default.
-    /// Use [`include_fails`](crate::request::GetUserScores::include_fails)
-    /// to include them.
+    ///   Use [`include_fails`](crate::request::GetUserScores::include_fails)
+    ///   to include them.
     /// - For the `firsts` score type, `pp` will only be `Some` if the map
-    /// is not loved.
+    ///   is not loved.
     #[cfg(feature = "cache")]
     #[inline]
     pub fn user_scores(&self, user_id: impl Into<UserId>) -> GetUserScores<'_> {
@@ -542,6 +827,27 @@ impl Osu {
         GetUsers::new(self, user_ids)
     }
 
+    /// Get a vec of [`UserCompact`](crate::model::user::UserCompact)
+    /// by their user id or username.
+    ///
+    /// The users are requested in chunks of 50 so for large amounts of
+    /// users this will send multiple requests.
+    ///
+    /// Users that could not be found are omitted.
+    /// The order of the resulting users is not guaranteed to match the given order.
+    ///
+    /// The `cache` feature will store the usernames of all retrieved users.
+    ///
+    /// Filled options will be: `country`, `cover`, and `groups`.
+    #[inline]
+    pub fn users_lookup<I, U>(&self, users: I) -> GetUsersLookup<'_>
+    where
+        I: IntoIterator<Item = U>,
+        U: Into<UserId>,
+    {
+        GetUsersLookup::new(self, users)
+    }
+
     /// Get a [`WikiPage`](crate::model::wiki::WikiPage) or image data.
     ///
     /// `locale` adjusts the language, e.g. `en` for english, `de` for german, ...
@@ -577,6 +883,11 @@ impl Osu {
         }
     }
 
+    #[inline]
+    pub(crate) fn rankings_cache(&self) -> &RankingsCache {
+        &self.inner.rankings_cache
+    }
+
     #[cfg(feature = "cache")]
     pub(crate) fn update_cache(&self, user_id: u32, username: &Username) {
         let mut name = username.to_owned();
@@ -611,6 +922,8 @@ pub(crate) struct OsuRef {
     auth_kind: AuthorizationKind,
     token: RwLock<Token>,
     retries: usize,
+    api_version: u32,
+    rankings_cache: RankingsCache,
 }
 
 static MY_USER_AGENT: &str = concat!(
@@ -624,6 +937,7 @@ static MY_USER_AGENT: &str = concat!(
 const APPLICATION_JSON: &str = "application/json";
 const X_API_VERSION: &str = "x-api-version";
 
+/// Default value for the `x-api-version` header
 const API_VERSION: u32 = 20220705;
 
 impl OsuRef {
@@ -709,7 +1023,7 @@ impl OsuRef {
                 .uri(url.as_str())
                 .header(AUTHORIZATION, value)
                 .header(USER_AGENT, MY_USER_AGENT)
-                .header(X_API_VERSION, API_VERSION)
+                .header(X_API_VERSION, self.api_version)
                 .header(ACCEPT, APPLICATION_JSON)
                 .header(CONTENT_LENGTH, bytes.len());
 
@@ -752,7 +1066,7 @@ impl OsuRef {
             .map_err(|source| OsuError::ChunkingResponse { source })?;
 
         match status {
-            StatusCode::OK => return Ok(bytes),
+            StatusCode::OK | StatusCode::NO_CONTENT => return Ok(bytes),
             StatusCode::NOT_FOUND => return Err(OsuError::NotFound),
             StatusCode::SERVICE_UNAVAILABLE => {
                 let body = String::from_utf8_lossy(&bytes).into_owned();
@@ -174,6 +174,10 @@ pub enum ParsingError {
     ModsU32(u32),
     /// Failed to parse a String into [`GameMods`](crate::model::GameMods)
     ModsStr(String),
+    /// Failed to parse a String into a [`NowPlaying`](crate::model::NowPlaying)
+    NowPlaying(String),
+    /// Failed to parse a String into an [`OsuLink`](crate::model::OsuLink)
+    OsuLink(String),
     /// Failed to parse an i8 into a [`RankStatus`](crate::model::beatmap::RankStatus)
     RankStatus(i8),
     /// Failed to parse a u8 into a [`ScoringType`](crate::model::matches::ScoringType)
@@ -194,6 +198,8 @@ impl fmt::Display for ParsingError {
             Self::Language(n) => write!(f, "failed to parse {} into Language", n),
             Self::ModsU32(n) => write!(f, "failed to parse {} into GameMods", n),
             Self::ModsStr(s) => write!(f, "failed to parse `{}` into GameMods", s),
+            Self::NowPlaying(s) => write!(f, "failed to parse `{}` into NowPlaying", s),
+            Self::OsuLink(s) => write!(f, "failed to parse `{}` into OsuLink", s),
             Self::RankStatus(n) => write!(f, "failed to parse {} into RankStatus", n),
             Self::ScoringType(n) => write!(f, "failed to parse {} into ScoringType", n),
             Self::Team(n) => write!(f, "failed to parse {} into Team", n),
@@ -31,21 +31,32 @@
 //! - `beatmapsets/{mapset_id}`: The beatmapset including all of its difficulty beatmaps
 //! - `beatmapsets/events`: Various events around a beatmapset such as status, genre, or language updates, kudosu transfers, or new issues
 //! - `beatmapsets/search`: Search for beatmapsets; the same search as on the osu! website
+//! - `beatmapsets/{mapset_id}/favourites`: Add or remove a beatmapset from the authenticated user's favourites (requires OAuth)
+//! - `changelog`: List of builds and update streams
+//! - `changelog/{stream}/{version}`: A specific build including its changelog entries
 //! - `comments`: Most recent comments and their replies up to two levels deep
+//! - `comments[/{comment_id}[/vote]]`: Post, edit, delete, or vote on comments (requires OAuth)
+//! - `events`: The global feed of recent events such as achieved medals or ranks on beatmaps
 //! - `forums/topics/{topic_id}`: A forum topic and its posts
 //! - `matches`: List of currently open multiplayer lobbies
 //! - `matches/{match_id}`: More specific data about a specific multiplayer lobby including participating players and occured events
 //! - `me[/{mode}]`: Detailed info about the authenticated user [in the specified mode] (requires OAuth)
+//! - `me/beatmapset-favourites`: Beatmapset ids of the authenticated user's favourite beatmapsets (requires OAuth)
 //! - `news`: Recent news
+//! - `news/{news}`: A specific news post including its content
+//! - `notifications[/mark-read]`: Notifications of the authenticated user and marking them as read (requires OAuth)
 //! - `rankings/{mode}/{ranking_type}`: The global leaderboard of either performance points, ranked score, countries, or a spotlight
 //! - `users/{user_id}/{recent_activity}`: List of a user's recent events like achieved medals, ranks on a beatmaps, username changes, supporter status updates, beatmapset status updates, ...
-//! - `scores/{mode}/{score_id}`: A specific score including its beatmap, beatmapset, and user
+//! - `scores/[{mode}/]{score_id}`: A specific score including its beatmap, beatmapset, and user
+//! - `scores/[{mode}/]{score_id}/download`: The replay of a score (requires OAuth)
+//! - `search`: Users and wiki pages matching a query
 //! - `seasonal-backgrounds`: List of seasonal backgrounds i.e. their URL and artists
 //! - `spotlights`: List of overviews of all spotlights
 //! - `users/{user_id}[/{mode}]`: Detailed info about a user [in the specified mode]
 //! - `users/{user_id}/{beatmapsets/{map_type}`: List of beatmapsets either created, favourited, or most played by the user
 //! - `users/{user_id}/kudosu`: A user's recent kudosu transfers
 //! - `users/{user_id}/scores/{score_type}`: Either top, recent, pinned, or global #1 scores of a user
+//! - `users/lookup`: Up to 50 users at once by their user id or username
 //! - `wiki/{locale}[/{path}]`: The general wiki page or a specific topic if the path is specified
 //!
 //! The api itself provides a bunch more endpoints which are not yet implemented because they're really niche and/or missing any documentation.
@@ -125,8 +136,12 @@ mod routing;
 pub mod error;
 /// All available data types provided by the api
 pub mod model;
+/// Calculate performance points locally based on difficulty attributes
+pub mod pp;
 /// Requesting-structs that implement [`Future`](std::future::Future) for each endpoint
 pub mod request;
+/// Analyze tournament matches based on a mappool and a format
+pub mod tournament;
 
 #[cfg(feature = "metrics")]
 mod metrics;
@@ -145,9 +160,10 @@ pub mod prelude {
         client::Scope,
         error::OsuError,
         model::{
-            beatmap::*, comments::*, forum::*, kudosu::*, matches::*, news::*, ranking::*,
-            recent_event::*, score::*, seasonal_backgrounds::*, user::*, wiki::*, Cursor, GameMode,
-            GameMods, Grade,
+            beatmap::*, changelog::*, comments::*, forum::*, kudosu::*, matches::*, news::*,
+            notification::*, ranking::*, recent_event::*, score::*, search::*,
+            seasonal_backgrounds::*, user::*, wiki::*, Cursor, GameMode, GameMods, Grade, LazerMod,
+            LazerMods, NowPlaying, NowPlayingStatus, OsuLink,
         },
         request::UserId,
         Osu, OsuBuilder, OsuResult,
@@ -11,6 +11,7 @@ pub(crate) struct Metrics {
     pub(crate) beatmap_difficulty_attributes: IntCounter,
     pub(crate) beatmaps: IntCounter,
     pub(crate) beatmapset: IntCounter,
+    pub(crate) beatmapset_favourite: IntCounter,
     pub(crate) beatmapset_from_map_id: IntCounter,
     pub(crate) beatmapset_events: IntCounter,
     pub(crate) beatmapset_search: IntCounter,
@@ -18,7 +19,16 @@ pub(crate) struct Metrics {
     #[cfg(feature = "cache")]
     pub(crate) cache_size: IntCounter,
 
+    pub(crate) changelog: IntCounter,
+    pub(crate) changelog_build: IntCounter,
+
     pub(crate) comments: IntCounter,
+    pub(crate) post_comment: IntCounter,
+    pub(crate) edit_comment: IntCounter,
+    pub(crate) delete_comment: IntCounter,
+    pub(crate) vote_comment: IntCounter,
+
+    pub(crate) events: IntCounter,
 
     pub(crate) forum_posts: IntCounter,
 
@@ -26,6 +36,10 @@ pub(crate) struct Metrics {
     pub(crate) match_list: IntCounter,
 
     pub(crate) news: IntCounter,
+    pub(crate) news_post: IntCounter,
+
+    pub(crate) notifications: IntCounter,
+    pub(crate) notifications_mark_read: IntCounter,
 
     pub(crate) chart_rankings: IntCounter,
     pub(crate) country_rankings: IntCounter,
@@ -33,6 +47,8 @@ pub(crate) struct Metrics {
     pub(crate) score_rankings: IntCounter,
     pub(crate) spotlights: IntCounter,
 
+    pub(crate) search: IntCounter,
+
     pub(crate) seasonal_backgrounds: IntCounter,
 
     pub(crate) score: IntCounter,
@@ -40,6 +56,7 @@ pub(crate) struct Metrics {
     pub(crate) replay: IntCounter,
 
     pub(crate) own_data: IntCounter,
+    pub(crate) own_beatmapset_favourites: IntCounter,
     pub(crate) user: IntCounter,
     pub(crate) user_beatmapsets: IntCounter,
     pub(crate) recent_events: IntCounter,
@@ -50,6 +67,7 @@ pub(crate) struct Metrics {
     pub(crate) user_first_scores: IntCounter,
     pub(crate) user_pinned_scores: IntCounter,
     pub(crate) users: IntCounter,
+    pub(crate) users_lookup: IntCounter,
 
     pub(crate) wiki: IntCounter,
 }
@@ -68,6 +86,7 @@ impl Metrics {
                 .with_label_values(&["Beatmap difficulty attributes"]),
             beatmaps: counters.with_label_values(&["Beatmaps"]),
             beatmapset: counters.with_label_values(&["Beatmapset"]),
+            beatmapset_favourite: counters.with_label_values(&["Beatmapset favourite"]),
             beatmapset_events: counters.with_label_values(&["Beatmapset events"]),
             beatmapset_from_map_id: counters.with_label_values(&["Beatmapset from Beatmap ID"]),
             beatmapset_search: counters.with_label_values(&["Beatmapset search"]),
@@ -75,7 +94,16 @@ impl Metrics {
             #[cfg(feature = "cache")]
             cache_size: counters.with_label_values(&["Cached Username-UserId pairs"]),
 
+            changelog: counters.with_label_values(&["Changelog"]),
+            changelog_build: counters.with_label_values(&["Changelog build"]),
+
             comments: counters.with_label_values(&["Comments"]),
+            post_comment: counters.with_label_values(&["Post comment"]),
+            edit_comment: counters.with_label_values(&["Edit comment"]),
+            delete_comment: counters.with_label_values(&["Delete comment"]),
+            vote_comment: counters.with_label_values(&["Vote comment"]),
+
+            events: counters.with_label_values(&["Events"]),
 
             forum_posts: counters.with_label_values(&["Forum posts"]),
 
@@ -83,6 +111,10 @@ impl Metrics {
             match_list: counters.with_label_values(&["Match list"]),
 
             news: counters.with_label_values(&["News"]),
+            news_post: counters.with_label_values(&["News post"]),
+
+            notifications: counters.with_label_values(&["Notifications"]),
+            notifications_mark_read: counters.with_label_values(&["Mark notifications read"]),
 
             chart_rankings: counters.with_label_values(&["Chart rankings"]),
             country_rankings: counters.with_label_values(&["Country rankings"]),
@@ -90,6 +122,8 @@ impl Metrics {
             score_rankings: counters.with_label_values(&["Score rankings"]),
             spotlights: counters.with_label_values(&["Spotlights"]),
 
+            search: counters.with_label_values(&["Search"]),
+
             seasonal_backgrounds: counters.with_label_values(&["Seasonal backgrounds"]),
 
             score: counters.with_label_values(&["Score"]),
@@ -97,6 +131,7 @@ impl Metrics {
             replay: counters.with_label_values(&["Replay"]),
 
             own_data: counters.with_label_values(&["Own Data"]),
+            own_beatmapset_favourites: counters.with_label_values(&["Own beatmapset favourites"]),
             user: counters.with_label_values(&["User"]),
             user_beatmapsets: counters.with_label_values(&["User mapsets"]),
             recent_events: counters.with_label_values(&["User events"]),
@@ -107,6 +142,7 @@ impl Metrics {
             user_first_scores: counters.with_label_values(&["User first scores"]),
             user_pinned_scores: counters.with_label_values(&["User pinned scores"]),
             users: counters.with_label_values(&["Users"]),
+            users_lookup: counters.with_label_values(&["Users lookup"]),
 
             wiki: counters.with_label_values(&["Wiki"]),
 
@@ -167,6 +167,11 @@ pub(crate) struct Beatmaps {
     pub(crate) maps: Vec<BeatmapCompact>,
 }
 
+#[derive(Deserialize)]
+pub(crate) struct BeatmapsetFavouriteCount {
+    pub(crate) favourite_count: u32,
+}
+
 #[derive(Deserialize)]
 pub(crate) struct BeatmapDifficultyAttributesWrapper {
     pub attributes: BeatmapDifficultyAttributes,
@@ -0,0 +1,317 @@
+use super::{
+    beatmap_::{Beatmap, BeatmapCompact},
+    GameMode, GameMods,
+};
+
+/// Attributes of a [`Beatmap`] after applying mods, see [`Beatmap::attributes`].
+///
+/// Times are given in milliseconds of real time i.e. the clock rate is taken into account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeatmapAttributes {
+    pub ar: f32,
+    pub od: f32,
+    pub cs: f32,
+    pub hp: f32,
+    pub bpm: f32,
+    pub seconds_drain: u32,
+    pub seconds_total: u32,
+    /// The clock rate of the mods e.g. 1.5 for DT
+    pub clock_rate: f32,
+    /// Milliseconds between the appearance of a hit object and the time it should be hit
+    pub approach_time: f32,
+    pub hit_windows: HitWindows,
+}
+
+/// Hit windows in milliseconds for each hit result available in a [`GameMode`].
+///
+/// A hit within the given milliseconds before or after an object's time results in the corresponding hit result.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct HitWindows {
+    /// Hit window for 320s / gekis, only available for `GameMode::Mania`
+    pub perfect: Option<f32>,
+    /// Hit window for 300s, not available for `GameMode::Catch`
+    pub great: Option<f32>,
+    /// Hit window for 200s / katus, only available for `GameMode::Mania`
+    pub good: Option<f32>,
+    /// Hit window for 100s, not available for `GameMode::Catch`
+    pub ok: Option<f32>,
+    /// Hit window for 50s, only available for `GameMode::Osu` and `GameMode::Mania`
+    pub meh: Option<f32>,
+}
+
+impl Beatmap {
+    /// Calculate the map's attributes after applying the given mods in the given mode.
+    ///
+    /// HR and EZ scale AR, OD, CS, and HP, except for OD and CS in osu!mania, while the clock rate
+    /// of DT / NC and HT adjusts AR and OD through their approach time and hit windows, as well as
+    /// BPM and lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # use rosu_v2::model::{beatmap::Beatmap, GameMode, GameMods};
+    /// # fn example(map: &Beatmap) {
+    /// let attrs = map.attributes(GameMods::HardRock | GameMods::DoubleTime, GameMode::Osu);
+    ///
+    /// println!("AR{:.2} OD{:.2} {} BPM", attrs.ar, attrs.od, attrs.bpm);
+    /// # }
+    /// ```
+    pub fn attributes(&self, mods: GameMods, mode: GameMode) -> BeatmapAttributes {
+        let clock_rate = mods.clock_rate();
+
+        let scale = |value: f32, hr_factor: f32| {
+            if mods.contains(GameMods::HardRock) {
+                (value * hr_factor).min(10.0)
+            } else if mods.contains(GameMods::Easy) {
+                value * 0.5
+            } else {
+                value
+            }
+        };
+
+        let hp = scale(self.hp, 1.4);
+        let ar = scale(self.ar, 1.4);
+
+        // osu!mania applies HR and EZ directly to the hit windows instead of OD
+        // and its CS is the key count which is not affected at all
+        let (cs, od) = if mode == GameMode::Mania {
+            (self.cs, self.od)
+        } else {
+            (scale(self.cs, 1.3), scale(self.od, 1.4))
+        };
+
+        let approach_time = ar_to_ms(ar) / clock_rate;
+        let hit_windows = hit_windows(od, mods, mode, clock_rate);
+
+        let od = match (mode, hit_windows.great) {
+            (GameMode::Osu, Some(great)) => (80.0 - great) / 6.0,
+            (GameMode::Taiko, Some(great)) => (50.0 - great) / 3.0,
+            _ => od,
+        };
+
+        BeatmapAttributes {
+            ar: ms_to_ar(approach_time),
+            od,
+            cs,
+            hp,
+            bpm: self.bpm * clock_rate,
+            seconds_drain: adjust_seconds(self.seconds_drain, clock_rate),
+            seconds_total: adjust_seconds(self.seconds_total, clock_rate),
+            clock_rate,
+            approach_time,
+            hit_windows,
+        }
+    }
+}
+
+impl BeatmapCompact {
+    /// Return the total length of the map in seconds after applying the mods' clock rate.
+    ///
+    /// For other mod-adjusted attributes, see [`Beatmap::attributes`].
+    #[inline]
+    pub fn seconds_total_with_mods(&self, mods: GameMods) -> u32 {
+        adjust_seconds(self.seconds_total, mods.clock_rate())
+    }
+}
+
+fn adjust_seconds(seconds: u32, clock_rate: f32) -> u32 {
+    (seconds as f32 / clock_rate).round() as u32
+}
+
+fn ar_to_ms(ar: f32) -> f32 {
+    if ar < 5.0 {
+        1200.0 + 600.0 * (5.0 - ar) / 5.0
+    } else {
+        1200.0 - 750.0 * (ar - 5.0) / 5.0
+    }
+}
+
+fn ms_to_ar(ms: f32) -> f32 {
+    if ms > 1200.0 {
+        5.0 - (ms - 1200.0) / 120.0
+    } else {
+        5.0 + (1200.0 - ms) / 150.0
+    }
+}
+
+fn hit_windows(od: f32, mods: GameMods, mode: GameMode, clock_rate: f32) -> HitWindows {
+    let adjust = |ms: f32| Some(ms / clock_rate);
+
+    match mode {
+        GameMode::Osu => HitWindows {
+            great: adjust(80.0 - 6.0 * od),
+            ok: adjust(140.0 - 8.0 * od),
+            meh: adjust(200.0 - 10.0 * od),
+            ..Default::default()
+        },
+        GameMode::Taiko => {
+            let ok = if od < 5.0 {
+                120.0 - 8.0 * od
+            } else {
+                110.0 - 6.0 * od
+            };
+
+            HitWindows {
+                great: adjust(50.0 - 3.0 * od),
+                ok: adjust(ok),
+                ..Default::default()
+            }
+        }
+        GameMode::Catch => HitWindows::default(),
+        GameMode::Mania => {
+            let factor = if mods.contains(GameMods::HardRock) {
+                1.0 / 1.4
+            } else if mods.contains(GameMods::Easy) {
+                1.4
+            } else {
+                1.0
+            };
+
+            let window = |ms: f32| adjust(ms * factor);
+
+            HitWindows {
+                perfect: window(16.0),
+                great: window(64.0 - 3.0 * od),
+                good: window(97.0 - 3.0 * od),
+                ok: window(127.0 - 3.0 * od),
+                meh: window(151.0 - 3.0 * od),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::beatmap::RankStatus;
+
+    use time::OffsetDateTime;
+
+    fn map(mode: GameMode) -> Beatmap {
+        Beatmap {
+            ar: 9.0,
+            bpm: 180.0,
+            checksum: None,
+            convert: false,
+            count_circles: 500,
+            count_sliders: 200,
+            count_spinners: 1,
+            creator_id: 2,
+            cs: 4.0,
+            deleted_at: None,
+            fail_times: None,
+            hp: 6.0,
+            is_scoreable: true,
+            last_updated: OffsetDateTime::UNIX_EPOCH,
+            map_id: 1,
+            mapset: None,
+            mapset_id: 1,
+            max_combo: None,
+            mode,
+            od: 8.0,
+            passcount: 0,
+            playcount: 0,
+            seconds_drain: 90,
+            seconds_total: 120,
+            stars: 5.0,
+            status: RankStatus::Ranked,
+            url: String::new(),
+            version: String::new(),
+        }
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 0.001,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn approach_time_roundtrip() {
+        for ar in [0.0, 3.5, 5.0, 8.7, 10.0] {
+            assert!((ms_to_ar(ar_to_ms(ar)) - ar).abs() < 0.001);
+        }
+
+        // AR9 with DT
+        assert!((ms_to_ar(ar_to_ms(9.0) / 1.5) - 10.333).abs() < 0.001);
+    }
+
+    #[test]
+    fn osu_hit_windows() {
+        let windows = hit_windows(8.0, GameMods::DoubleTime, GameMode::Osu, 1.5);
+
+        assert_eq!(windows.great, Some(32.0 / 1.5));
+        assert_eq!(windows.perfect, None);
+        assert!((((80.0 - windows.great.unwrap()) / 6.0) - 9.778).abs() < 0.001);
+    }
+
+    #[test]
+    fn hard_rock_caps_at_ten() {
+        let attrs = map(GameMode::Osu).attributes(GameMods::HardRock, GameMode::Osu);
+
+        assert_close(attrs.ar, 10.0);
+        assert_close(attrs.od, 10.0);
+        assert_close(attrs.cs, 5.2);
+        assert_close(attrs.hp, 8.4);
+        assert_close(attrs.approach_time, 450.0);
+        assert_eq!(attrs.clock_rate, 1.0);
+    }
+
+    #[test]
+    fn easy_halves() {
+        let attrs = map(GameMode::Osu).attributes(GameMods::Easy, GameMode::Osu);
+
+        assert_close(attrs.ar, 4.5);
+        assert_close(attrs.od, 4.0);
+        assert_close(attrs.cs, 2.0);
+        assert_close(attrs.hp, 3.0);
+        assert_close(attrs.hit_windows.great.unwrap(), 56.0);
+    }
+
+    #[test]
+    fn double_time_adjusts_clock_rate() {
+        let attrs = map(GameMode::Osu).attributes(GameMods::DoubleTime, GameMode::Osu);
+
+        assert_close(attrs.clock_rate, 1.5);
+        assert_close(attrs.ar, 10.333);
+        assert_close(attrs.od, 9.778);
+        assert_close(attrs.cs, 4.0);
+        assert_close(attrs.bpm, 270.0);
+        assert_eq!(attrs.seconds_drain, 60);
+        assert_eq!(attrs.seconds_total, 80);
+    }
+
+    #[test]
+    fn mania_keeps_cs_and_od() {
+        let map = map(GameMode::Mania);
+
+        let nomod = map.attributes(GameMods::NoMod, GameMode::Mania);
+        let hr = map.attributes(GameMods::HardRock, GameMode::Mania);
+        let ez = map.attributes(GameMods::Easy, GameMode::Mania);
+
+        for attrs in [&nomod, &hr, &ez] {
+            assert_close(attrs.cs, 4.0);
+            assert_close(attrs.od, 8.0);
+        }
+
+        assert_close(nomod.hit_windows.perfect.unwrap(), 16.0);
+        assert_close(nomod.hit_windows.great.unwrap(), 40.0);
+        assert_close(nomod.hit_windows.good.unwrap(), 73.0);
+        assert_close(nomod.hit_windows.ok.unwrap(), 103.0);
+        assert_close(nomod.hit_windows.meh.unwrap(), 127.0);
+        assert_close(hr.hit_windows.great.unwrap(), 40.0 / 1.4);
+        assert_close(ez.hit_windows.great.unwrap(), 40.0 * 1.4);
+    }
+
+    #[test]
+    fn catch_has_no_hit_windows() {
+        let attrs = map(GameMode::Osu).attributes(GameMods::HardRock, GameMode::Catch);
+
+        assert_eq!(attrs.hit_windows, HitWindows::default());
+        assert_close(attrs.cs, 5.2);
+        assert_close(attrs.ar, 10.0);
+        assert_close(attrs.od, 10.0);
+    }
+}
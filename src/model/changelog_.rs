@@ -0,0 +1,242 @@
+use super::serde_;
+use crate::{prelude::Username, request::GetChangelogBuild, Osu};
+
+use serde::Deserialize;
+use std::fmt;
+use time::OffsetDateTime;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// A list of builds, the parameters used for the listing, and all update streams.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+// TODO
+// #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Changelog {
+    /// Builds ordered by descending build id
+    pub builds: Vec<ChangelogBuild>,
+    /// The parameters that were used to filter the builds
+    pub search: ChangelogSearch,
+    /// All available update streams, each including its latest build
+    pub streams: Vec<UpdateStream>,
+}
+
+/// A specific build of the osu! client or website.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+// TODO
+// #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChangelogBuild {
+    #[serde(rename = "id")]
+    pub build_id: u32,
+    /// Only available when requesting a single build or the changelog listing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog_entries: Option<Vec<ChangelogEntry>>,
+    #[serde(with = "serde_::datetime")]
+    pub created_at: OffsetDateTime,
+    pub display_version: String,
+    /// The stream this build belongs to; not available for a stream's latest build
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_stream: Option<UpdateStream>,
+    /// Amount of users on this build
+    #[serde(rename = "users")]
+    pub user_count: u32,
+    pub version: Option<String>,
+    /// Only available when requesting a single build
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub versions: Option<ChangelogVersions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub youtube_id: Option<String>,
+}
+
+impl ChangelogBuild {
+    /// Request the next build of the same stream if there is one.
+    ///
+    /// Only works if `versions` and `update_stream` are `Some`.
+    #[inline]
+    pub fn get_next<'o>(&self, osu: &'o Osu) -> Option<GetChangelogBuild<'o>> {
+        let next = self.versions.as_ref()?.next.as_deref()?;
+
+        self.get_sibling(osu, next)
+    }
+
+    /// Request the previous build of the same stream if there is one.
+    ///
+    /// Only works if `versions` and `update_stream` are `Some`.
+    #[inline]
+    pub fn get_previous<'o>(&self, osu: &'o Osu) -> Option<GetChangelogBuild<'o>> {
+        let previous = self.versions.as_ref()?.previous.as_deref()?;
+
+        self.get_sibling(osu, previous)
+    }
+
+    fn get_sibling<'o>(
+        &self,
+        osu: &'o Osu,
+        sibling: &ChangelogBuild,
+    ) -> Option<GetChangelogBuild<'o>> {
+        let stream = self.update_stream.as_ref()?;
+        let version = sibling.version.as_deref()?;
+
+        Some(osu.changelog_build(stream.name.as_str(), version))
+    }
+}
+
+impl PartialEq for ChangelogBuild {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.build_id == other.build_id && self.created_at == other.created_at
+    }
+}
+
+impl Eq for ChangelogBuild {}
+
+/// A single change within a build.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChangelogEntry {
+    #[serde(rename = "id")]
+    pub entry_id: Option<u32>,
+    /// Free-form category such as "Gameplay", "Code quality", or "Reliability"
+    pub category: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub created_at: Option<OffsetDateTime>,
+    #[serde(rename = "type")]
+    pub entry_type: ChangelogEntryType,
+    pub github_pull_request_id: Option<u32>,
+    pub github_url: Option<String>,
+    /// Only `None` if the user could not be resolved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_user: Option<GithubUser>,
+    /// Whether the change is considered a major change
+    pub major: bool,
+    /// Markdown version of the entry's message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// HTML version of the entry's message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_html: Option<String>,
+    /// Repository name such as `ppy/osu`
+    pub repository: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
+impl PartialEq for ChangelogEntry {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.entry_id == other.entry_id && self.title == other.title
+    }
+}
+
+impl Eq for ChangelogEntry {}
+
+/// The kind of change of a [`ChangelogEntry`].
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvDeserialize, RkyvSerialize),
+    archive(as = "Self")
+)]
+pub enum ChangelogEntryType {
+    /// A new addition
+    #[serde(rename = "add")]
+    Add,
+    /// A bug fix
+    #[serde(rename = "fix")]
+    Fix,
+    /// Anything else
+    #[serde(rename = "misc")]
+    Misc,
+}
+
+impl fmt::Display for ChangelogEntryType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Self::Add => "add",
+            Self::Fix => "fix",
+            Self::Misc => "misc",
+        };
+
+        f.write_str(kind)
+    }
+}
+
+/// The parameters that were used to filter a [`Changelog`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChangelogSearch {
+    pub from: Option<String>,
+    pub limit: u32,
+    pub max_id: Option<u32>,
+    pub stream: Option<String>,
+    pub to: Option<String>,
+}
+
+/// The builds before and after a [`ChangelogBuild`] within the same stream.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+// TODO
+// #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChangelogVersions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<ChangelogBuild>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<Box<ChangelogBuild>>,
+}
+
+/// The GitHub user that authored a [`ChangelogEntry`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct GithubUser {
+    #[serde(rename = "id")]
+    pub github_user_id: Option<u32>,
+    pub display_name: String,
+    pub github_url: Option<String>,
+    pub github_username: Option<String>,
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameMap))]
+    pub osu_username: Option<Username>,
+    /// The osu! user id if the GitHub account is linked to one
+    pub user_id: Option<u32>,
+    pub user_url: Option<String>,
+}
+
+/// A stream of builds such as `stable40`, `lazer`, or `web`.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+// TODO
+// #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct UpdateStream {
+    #[serde(rename = "id")]
+    pub stream_id: u32,
+    pub display_name: Option<String>,
+    pub is_featured: bool,
+    /// Only available within the stream list of a [`Changelog`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest_build: Option<Box<ChangelogBuild>>,
+    /// Internal name that is used to filter builds by stream
+    pub name: String,
+    /// Amount of users on this stream; only available within the stream list of a [`Changelog`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u32>,
+}
+
+impl PartialEq for UpdateStream {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.stream_id == other.stream_id && self.latest_build == other.latest_build
+    }
+}
+
+impl Eq for UpdateStream {}
@@ -96,6 +96,7 @@ impl Eq for Comment {}
 // #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct CommentBundle {
     /// ID of the object the comment is attached to
+    #[serde(default)]
     pub commentable_meta: Vec<CommentableMeta>,
     /// List of comments ordered according to `sort`
     pub comments: Vec<Comment>,
@@ -106,6 +107,7 @@ pub struct CommentBundle {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_more_id: Option<u32>,
     /// Related comments; e.g. parent comments and nested replies
+    #[serde(default)]
     pub included_comments: Vec<Comment>,
     /// Pinned comments
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -177,6 +179,39 @@ impl fmt::Display for CommentSort {
     }
 }
 
+/// Types of objects that comments can be attached to
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvDeserialize, RkyvSerialize),
+    archive(as = "Self")
+)]
+pub enum CommentableType {
+    /// A beatmapset
+    #[serde(rename = "beatmapset")]
+    Beatmapset,
+    /// A changelog build
+    #[serde(rename = "build")]
+    Build,
+    /// A news post
+    #[serde(rename = "news_post")]
+    NewsPost,
+}
+
+impl fmt::Display for CommentableType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Self::Beatmapset => "beatmapset",
+            Self::Build => "build",
+            Self::NewsPost => "news_post",
+        };
+
+        f.write_str(kind)
+    }
+}
+
 /// Metadata of the object that a comment is attached to.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -0,0 +1,1005 @@
+use crate::{
+    error::{OsuError, ParsingError},
+    model::GameMods,
+};
+
+use serde::{
+    de::{
+        value::MapAccessDeserializer, Error, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor,
+    },
+    Deserialize, Deserializer,
+};
+use std::{convert::TryFrom, fmt, iter::FromIterator, slice::Iter, str::FromStr, vec::IntoIter};
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+macro_rules! lazer_mods {
+    (@LEGACY $legacy:ident) => {
+        Some(GameMods::$legacy)
+    };
+    (@LEGACY) => {
+        None
+    };
+    (
+        $(
+            $( #[$meta:meta] )*
+            $variant:ident($acronym:literal $(, $legacy:ident )?) $( {
+                $(
+                    $( #[$field_meta:meta] )*
+                    $field:ident: $ty:ty,
+                )*
+            } )?
+        )*
+    ) => {
+        /// A single osu!lazer mod including its settings.
+        ///
+        /// Settings that were not specified are `None`.
+        /// Mods that are not known, or whose settings don't have the expected
+        /// names or types, are represented by [`LazerMod::Unknown`].
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+        #[non_exhaustive]
+        pub enum LazerMod {
+            $(
+                $( #[$meta] )*
+                $variant $( {
+                    $(
+                        $( #[$field_meta] )*
+                        $field: Option<$ty>,
+                    )*
+                } )?,
+            )*
+            /// Any mod without a typed representation
+            Unknown {
+                acronym: String,
+                settings: Vec<ModSetting>,
+            },
+        }
+
+        impl LazerMod {
+            /// The acronym of the mod e.g. `"DT"`.
+            pub fn acronym(&self) -> &str {
+                match self {
+                    $( Self::$variant { .. } => $acronym, )*
+                    Self::Unknown { acronym, .. } => acronym,
+                }
+            }
+
+            /// The legacy [`GameMods`] equivalent of the mod, if there is one.
+            ///
+            /// Note that the mod's settings are not taken into account.
+            pub fn legacy(&self) -> Option<GameMods> {
+                match self {
+                    $( Self::$variant { .. } => lazer_mods!(@LEGACY $( $legacy )?), )*
+                    Self::Unknown { .. } => None,
+                }
+            }
+
+            /// All specified settings of the mod.
+            pub fn settings(&self) -> Vec<ModSetting> {
+                match self {
+                    $(
+                        Self::$variant $( { $( $field, )* } )? => {
+                            #[allow(unused_mut)]
+                            let mut settings = Vec::new();
+
+                            $( $(
+                                if let Some(value) = $field {
+                                    settings.push(ModSetting {
+                                        name: stringify!($field).to_owned(),
+                                        value: value.to_setting(),
+                                    });
+                                }
+                            )* )?
+
+                            settings
+                        }
+                    )*
+                    Self::Unknown { settings, .. } => settings.clone(),
+                }
+            }
+
+            fn from_legacy(mods: GameMods) -> Option<Self> {
+                $( $(
+                    if mods == GameMods::$legacy {
+                        return Some(Self::from_parts($acronym, Vec::new()));
+                    }
+                )? )*
+
+                None
+            }
+
+            /// Create the mod from its acronym and its settings.
+            ///
+            /// If the acronym is not known or the settings do not match,
+            /// [`LazerMod::Unknown`] is returned.
+            #[allow(clippy::match_single_binding)]
+            pub fn from_parts(acronym: &str, settings: Vec<ModSetting>) -> Self {
+                match acronym {
+                    $(
+                        $acronym => {
+                            $( $( let mut $field = None; )* )?
+                            let mut typed = true;
+
+                            for setting in settings.iter() {
+                                match setting.name.as_str() {
+                                    $( $(
+                                        stringify!($field) => match SettingValue::from_setting(&setting.value) {
+                                            Some(value) => $field = Some(value),
+                                            None => typed = false,
+                                        },
+                                    )* )?
+                                    _ => typed = false,
+                                }
+                            }
+
+                            if typed {
+                                return Self::$variant $( { $( $field, )* } )?;
+                            }
+                        }
+                    )*
+                    _ => {}
+                }
+
+                Self::Unknown {
+                    acronym: acronym.to_owned(),
+                    settings,
+                }
+            }
+        }
+
+        impl fmt::Display for LazerMod {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.acronym())?;
+                let mut settings = SettingsFormatter { f, open: false };
+
+                match self {
+                    $(
+                        Self::$variant $( { $( $field, )* } )? => {
+                            $( $(
+                                if let Some(value) = $field {
+                                    settings.entry(stringify!($field), value)?;
+                                }
+                            )* )?
+                        }
+                    )*
+                    Self::Unknown { settings: unknown, .. } => {
+                        for setting in unknown {
+                            settings.entry(&setting.name, &setting.value)?;
+                        }
+                    }
+                }
+
+                settings.finish()
+            }
+        }
+    };
+}
+
+lazer_mods! {
+    /// Easy, legacy equivalent `GameMods::Easy`
+    Easy("EZ", Easy) {
+        /// Amount of extra lives
+        retries: u32,
+    }
+    /// No Fail, legacy equivalent `GameMods::NoFail`
+    NoFail("NF", NoFail)
+    /// Half Time, legacy equivalent `GameMods::HalfTime`
+    HalfTime("HT", HalfTime) {
+        /// Speed multiplier, defaults to 0.75
+        speed_change: f32,
+        /// Whether the pitch should be adjusted with the speed
+        adjust_pitch: bool,
+    }
+    /// Daycore
+    Daycore("DC") {
+        /// Speed multiplier, defaults to 0.75
+        speed_change: f32,
+    }
+    /// Hard Rock, legacy equivalent `GameMods::HardRock`
+    HardRock("HR", HardRock)
+    /// Sudden Death, legacy equivalent `GameMods::SuddenDeath`
+    SuddenDeath("SD", SuddenDeath) {
+        /// Whether the map should restart instead of failing
+        restart: bool,
+    }
+    /// Perfect, legacy equivalent `GameMods::Perfect`
+    Perfect("PF", Perfect) {
+        /// Whether the map should restart instead of failing
+        restart: bool,
+    }
+    /// Double Time, legacy equivalent `GameMods::DoubleTime`
+    DoubleTime("DT", DoubleTime) {
+        /// Speed multiplier, defaults to 1.5
+        speed_change: f32,
+        /// Whether the pitch should be adjusted with the speed
+        adjust_pitch: bool,
+    }
+    /// Nightcore, legacy equivalent `GameMods::NightCore`
+    Nightcore("NC", NightCore) {
+        /// Speed multiplier, defaults to 1.5
+        speed_change: f32,
+    }
+    /// Hidden, legacy equivalent `GameMods::Hidden`
+    Hidden("HD", Hidden) {
+        /// Whether only approach circles should fade
+        only_fade_approach_circles: bool,
+    }
+    /// Fade In, legacy equivalent `GameMods::FadeIn`
+    FadeIn("FI", FadeIn)
+    /// Flashlight, legacy equivalent `GameMods::Flashlight`
+    Flashlight("FL", Flashlight) {
+        /// Milliseconds until the flashlight reaches the cursor
+        follow_delay: f32,
+        /// Multiplier for the flashlight size
+        size_multiplier: f32,
+        /// Whether the flashlight shrinks with increasing combo
+        combo_based_size: bool,
+    }
+    /// Blinds
+    Blinds("BL")
+    /// Traceable
+    Traceable("TC")
+    /// Difficulty Adjust
+    DifficultyAdjust("DA") {
+        circle_size: f32,
+        approach_rate: f32,
+        drain_rate: f32,
+        overall_difficulty: f32,
+        /// Scroll speed for osu!taiko
+        scroll_speed: f32,
+        /// Whether values beyond the usual limits are allowed
+        extended_limits: bool,
+    }
+    /// Classic
+    Classic("CL") {
+        no_slider_head_accuracy: bool,
+        classic_note_lock: bool,
+        always_play_tail_sample: bool,
+        fade_hit_circle_early: bool,
+        classic_health: bool,
+    }
+    /// Random, legacy equivalent `GameMods::Random`
+    Random("RD", Random) {
+        /// Seed for the randomization
+        seed: u32,
+    }
+    /// Mirror, legacy equivalent `GameMods::Mirror`
+    Mirror("MR", Mirror)
+    /// Target Practice, legacy equivalent `GameMods::Target`
+    TargetPractice("TP", Target)
+    /// Relax, legacy equivalent `GameMods::Relax`
+    Relax("RX", Relax)
+    /// Autopilot, legacy equivalent `GameMods::Autopilot`
+    Autopilot("AP", Autopilot)
+    /// Spun Out, legacy equivalent `GameMods::SpunOut`
+    SpunOut("SO", SpunOut)
+    /// Autoplay, legacy equivalent `GameMods::Autoplay`
+    Autoplay("AT", Autoplay)
+    /// Cinema, legacy equivalent `GameMods::Cinema`
+    Cinema("CN", Cinema)
+    /// Score V2, legacy equivalent `GameMods::ScoreV2`
+    ScoreV2("SV2", ScoreV2)
+    /// Touch Device, legacy equivalent `GameMods::TouchDevice`
+    TouchDevice("TD", TouchDevice)
+    /// Wind Up
+    WindUp("WU") {
+        initial_rate: f32,
+        final_rate: f32,
+        adjust_pitch: bool,
+    }
+    /// Wind Down
+    WindDown("WD") {
+        initial_rate: f32,
+        final_rate: f32,
+        adjust_pitch: bool,
+    }
+    /// Adaptive Speed
+    AdaptiveSpeed("AS") {
+        initial_rate: f32,
+        adjust_pitch: bool,
+    }
+    /// One Key, legacy equivalent `GameMods::Key1`
+    Key1("1K", Key1)
+    /// Two Keys, legacy equivalent `GameMods::Key2`
+    Key2("2K", Key2)
+    /// Three Keys, legacy equivalent `GameMods::Key3`
+    Key3("3K", Key3)
+    /// Four Keys, legacy equivalent `GameMods::Key4`
+    Key4("4K", Key4)
+    /// Five Keys, legacy equivalent `GameMods::Key5`
+    Key5("5K", Key5)
+    /// Six Keys, legacy equivalent `GameMods::Key6`
+    Key6("6K", Key6)
+    /// Seven Keys, legacy equivalent `GameMods::Key7`
+    Key7("7K", Key7)
+    /// Eight Keys, legacy equivalent `GameMods::Key8`
+    Key8("8K", Key8)
+    /// Nine Keys, legacy equivalent `GameMods::Key9`
+    Key9("9K", Key9)
+    /// Ten Keys
+    Key10("10K")
+    /// Dual Stages, legacy equivalent `GameMods::KeyCoop`
+    DualStages("DS", KeyCoop)
+}
+
+impl LazerMod {
+    fn has_settings(&self) -> bool {
+        !self.settings().is_empty()
+    }
+}
+
+/// A named setting of a [`LazerMod`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ModSetting {
+    pub name: String,
+    pub value: ModSettingValue,
+}
+
+/// The value of a [`ModSetting`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub enum ModSettingValue {
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl ModSettingValue {
+    fn parse(s: &str) -> Self {
+        match s {
+            "true" => Self::Bool(true),
+            "false" => Self::Bool(false),
+            _ => s
+                .parse()
+                .map_or_else(|_| Self::String(s.to_owned()), Self::Number),
+        }
+    }
+}
+
+impl fmt::Display for ModSettingValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => fmt::Display::fmt(value, f),
+            Self::Number(value) => fmt::Display::fmt(value, f),
+            Self::String(value) => f.write_str(value),
+        }
+    }
+}
+
+trait SettingValue: Sized {
+    fn from_setting(value: &ModSettingValue) -> Option<Self>;
+    fn to_setting(&self) -> ModSettingValue;
+}
+
+impl SettingValue for bool {
+    #[inline]
+    fn from_setting(value: &ModSettingValue) -> Option<Self> {
+        match value {
+            ModSettingValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn to_setting(&self) -> ModSettingValue {
+        ModSettingValue::Bool(*self)
+    }
+}
+
+impl SettingValue for f32 {
+    #[inline]
+    fn from_setting(value: &ModSettingValue) -> Option<Self> {
+        match value {
+            ModSettingValue::Number(value) => Some(*value as f32),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn to_setting(&self) -> ModSettingValue {
+        ModSettingValue::Number(f64::from(*self))
+    }
+}
+
+impl SettingValue for u32 {
+    #[inline]
+    fn from_setting(value: &ModSettingValue) -> Option<Self> {
+        match value {
+            ModSettingValue::Number(value)
+                if value.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(value) =>
+            {
+                Some(*value as u32)
+            }
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn to_setting(&self) -> ModSettingValue {
+        ModSettingValue::Number(f64::from(*self))
+    }
+}
+
+/// Writes settings in the form `(1.3x,adjust_pitch=true)`
+struct SettingsFormatter<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    open: bool,
+}
+
+impl SettingsFormatter<'_, '_> {
+    fn entry(&mut self, name: &str, value: &dyn fmt::Display) -> fmt::Result {
+        self.f.write_str(if self.open { "," } else { "(" })?;
+        self.open = true;
+
+        if name == "speed_change" {
+            write!(self.f, "{}x", value)
+        } else {
+            write!(self.f, "{}={}", name, value)
+        }
+    }
+
+    fn finish(self) -> fmt::Result {
+        if self.open {
+            self.f.write_str(")")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A combination of osu!lazer mods.
+///
+/// Unlike [`GameMods`], this type can represent mods that only exist in osu!lazer
+/// as well as mod settings such as a custom speed.
+///
+/// # Example
+/// ```
+/// use rosu_v2::model::{GameMods, LazerMod, LazerMods};
+///
+/// let mods: LazerMods = "DT(1.3x)HDCL".parse().unwrap();
+/// assert_eq!(mods.len(), 3);
+/// assert_eq!(mods.clock_rate(), 1.3);
+/// assert_eq!(mods.to_string(), "DT(1.3x)HDCL");
+///
+/// // Custom settings and osu!lazer-only mods have no legacy representation
+/// assert_eq!(mods.try_legacy(), None);
+/// assert_eq!(mods.legacy(), GameMods::DoubleTime | GameMods::Hidden);
+///
+/// // Converting from and to GameMods is lossless
+/// let legacy = GameMods::Hidden | GameMods::NightCore;
+/// let mods = LazerMods::from(legacy);
+/// assert!(matches!(mods.iter().next(), Some(LazerMod::Hidden { .. })));
+/// assert_eq!(mods.try_legacy(), Some(legacy));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct LazerMods(pub Vec<LazerMod>);
+
+impl LazerMods {
+    /// Create an empty mod combination.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the amount of contained mods.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether no mods are contained i.e. NoMod.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the contained mods.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, LazerMod> {
+        self.0.iter()
+    }
+
+    /// Returns the mod with the given acronym, if it is contained.
+    pub fn get(&self, acronym: &str) -> Option<&LazerMod> {
+        self.0.iter().find(|m| m.acronym() == acronym)
+    }
+
+    /// Check whether a mod with the given acronym is contained.
+    #[inline]
+    pub fn contains(&self, acronym: &str) -> bool {
+        self.get(acronym).is_some()
+    }
+
+    /// Returns the legacy [`GameMods`] of all contained mods that have a legacy equivalent.
+    ///
+    /// Mods without legacy equivalent as well as mod settings are ignored.
+    pub fn legacy(&self) -> GameMods {
+        self.0
+            .iter()
+            .filter_map(LazerMod::legacy)
+            .fold(GameMods::NoMod, |mods, m| mods | m)
+    }
+
+    /// Returns the legacy [`GameMods`] if all contained mods have a legacy
+    /// equivalent and none of them has settings specified, `None` otherwise.
+    pub fn try_legacy(&self) -> Option<GameMods> {
+        self.0.iter().try_fold(GameMods::NoMod, |mods, m| {
+            if m.has_settings() {
+                None
+            } else {
+                m.legacy().map(|m| mods | m)
+            }
+        })
+    }
+
+    /// Returns the clock rate of the mods, taking custom speed settings into account.
+    ///
+    /// If no speed setting is specified, DT and NC have a clock rate of 1.5,
+    /// HT and DC have 0.75, and everything else has 1.0.
+    pub fn clock_rate(&self) -> f32 {
+        self.0
+            .iter()
+            .find_map(|m| match m {
+                LazerMod::DoubleTime { speed_change, .. }
+                | LazerMod::Nightcore { speed_change } => Some(speed_change.unwrap_or(1.5)),
+                LazerMod::HalfTime { speed_change, .. } | LazerMod::Daycore { speed_change } => {
+                    Some(speed_change.unwrap_or(0.75))
+                }
+                _ => None,
+            })
+            .unwrap_or(1.0)
+    }
+}
+
+impl From<GameMods> for LazerMods {
+    fn from(mods: GameMods) -> Self {
+        mods.iter().filter_map(LazerMod::from_legacy).collect()
+    }
+}
+
+impl From<Vec<LazerMod>> for LazerMods {
+    #[inline]
+    fn from(mods: Vec<LazerMod>) -> Self {
+        Self(mods)
+    }
+}
+
+impl FromIterator<LazerMod> for LazerMods {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = LazerMod>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for LazerMods {
+    type Item = LazerMod;
+    type IntoIter = IntoIter<LazerMod>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a LazerMods {
+    type Item = &'a LazerMod;
+    type IntoIter = Iter<'a, LazerMod>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for LazerMods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("NM");
+        }
+
+        for m in self.0.iter() {
+            fmt::Display::fmt(m, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for LazerMods {
+    type Err = OsuError;
+
+    /// Parses acronyms such as `"HDHR"`, optionally with settings in parentheses
+    /// e.g. `"DT(1.3x)HDCL"` or `"DA(approach_rate=9.5,extended_limits=true)"`.
+    ///
+    /// `Nx` is short for the setting `speed_change=N`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || OsuError::from(ParsingError::ModsStr(s.to_owned()));
+        let mut mods = Vec::new();
+        let mut rest = s.trim();
+
+        if rest.eq_ignore_ascii_case("NM") || rest.eq_ignore_ascii_case("NOMOD") {
+            return Ok(Self::default());
+        }
+
+        while !rest.is_empty() {
+            let is_long = |prefix: &str| {
+                ["SV2", "10K"]
+                    .iter()
+                    .any(|a| a.eq_ignore_ascii_case(prefix))
+            };
+
+            // `get` instead of indexing to not panic on non-ASCII input
+            let len = match rest.get(..3) {
+                Some(prefix) if is_long(prefix) => 3,
+                _ => 2,
+            };
+
+            let acronym = rest.get(..len).ok_or_else(err)?;
+
+            if !acronym.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                return Err(err());
+            }
+
+            let mut acronym = acronym.to_ascii_uppercase();
+            rest = &rest[len..];
+
+            if acronym == "V2" {
+                acronym = "SV2".to_owned();
+            }
+
+            let mut settings = Vec::new();
+
+            if let Some(suffix) = rest.strip_prefix('(') {
+                let end = suffix.find(')').ok_or_else(err)?;
+
+                for item in suffix[..end].split(',').map(str::trim) {
+                    let setting = match item.split_once('=') {
+                        Some((name, value)) => ModSetting {
+                            name: name.trim().to_owned(),
+                            value: ModSettingValue::parse(value.trim()),
+                        },
+                        None => {
+                            let speed = item.strip_suffix(|c| c == 'x' || c == 'X');
+                            let speed = speed.and_then(|n| n.parse().ok()).ok_or_else(err)?;
+
+                            ModSetting {
+                                name: "speed_change".to_owned(),
+                                value: ModSettingValue::Number(speed),
+                            }
+                        }
+                    };
+
+                    settings.push(setting);
+                }
+
+                rest = &suffix[end + 1..];
+            }
+
+            mods.push(LazerMod::from_parts(&acronym, settings));
+        }
+
+        Ok(Self(mods))
+    }
+}
+
+struct ModSettingValueVisitor;
+
+impl<'de> Visitor<'de> for ModSettingValueVisitor {
+    type Value = ModSettingValue;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a bool, number, or string")
+    }
+
+    #[inline]
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(ModSettingValue::Bool(v))
+    }
+
+    #[inline]
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(ModSettingValue::Number(v as f64))
+    }
+
+    #[inline]
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(ModSettingValue::Number(v as f64))
+    }
+
+    #[inline]
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(ModSettingValue::Number(v))
+    }
+
+    #[inline]
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(ModSettingValue::String(v.to_owned()))
+    }
+
+    #[inline]
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(ModSettingValue::String(v))
+    }
+}
+
+impl<'de> Deserialize<'de> for ModSettingValue {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_any(ModSettingValueVisitor)
+    }
+}
+
+#[derive(Default)]
+struct ModSettings(Vec<ModSetting>);
+
+struct ModSettingsVisitor;
+
+impl<'de> Visitor<'de> for ModSettingsVisitor {
+    type Value = ModSettings;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map of mod settings")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut settings = Vec::new();
+
+        while let Some((name, value)) = map.next_entry()? {
+            settings.push(ModSetting { name, value });
+        }
+
+        Ok(ModSettings(settings))
+    }
+
+    // Empty settings might be sent as empty list
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+
+        Ok(ModSettings::default())
+    }
+
+    #[inline]
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(ModSettings::default())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModSettings {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_any(ModSettingsVisitor)
+    }
+}
+
+#[derive(Deserialize)]
+struct RawLazerMod {
+    acronym: String,
+    #[serde(default)]
+    settings: ModSettings,
+}
+
+struct LazerModVisitor;
+
+impl<'de> Visitor<'de> for LazerModVisitor {
+    type Value = LazerMod;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a mod acronym or an object with an acronym and settings")
+    }
+
+    #[inline]
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(LazerMod::from_parts(v, Vec::new()))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let RawLazerMod { acronym, settings } =
+            <RawLazerMod as Deserialize>::deserialize(MapAccessDeserializer::new(map))?;
+
+        Ok(LazerMod::from_parts(&acronym, settings.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for LazerMod {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_any(LazerModVisitor)
+    }
+}
+
+struct LazerModsVisitor;
+
+impl<'de> Visitor<'de> for LazerModsVisitor {
+    type Value = LazerMods;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of mods, a u32, or a string")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut mods = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(m) = seq.next_element()? {
+            mods.push(m);
+        }
+
+        Ok(LazerMods(mods))
+    }
+
+    #[inline]
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        u32::try_from(v)
+            .ok()
+            .and_then(GameMods::from_bits)
+            .map(LazerMods::from)
+            .ok_or_else(|| {
+                Error::invalid_value(
+                    Unexpected::Unsigned(v),
+                    &"a valid u32 representing a mod combination",
+                )
+            })
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        match v.parse::<u64>() {
+            Ok(bits) => self.visit_u64(bits),
+            Err(_) => v
+                .parse()
+                .map_err(|_| Error::invalid_value(Unexpected::Str(v), &"a combination of mods")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LazerMods {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_any(LazerModsVisitor)
+    }
+}
+
+#[cfg(feature = "serialize")]
+mod serialize {
+    use super::{LazerMod, LazerMods, ModSetting, ModSettingValue};
+
+    use serde::ser::{Serialize, SerializeMap, Serializer};
+
+    struct Settings<'a>(&'a [ModSetting]);
+
+    impl Serialize for Settings<'_> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            s.collect_map(self.0.iter().map(|setting| (&setting.name, &setting.value)))
+        }
+    }
+
+    impl Serialize for ModSettingValue {
+        #[inline]
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::Bool(value) => s.serialize_bool(*value),
+                Self::Number(value) => s.serialize_f64(*value),
+                Self::String(value) => s.serialize_str(value),
+            }
+        }
+    }
+
+    impl Serialize for LazerMod {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            let settings = self.settings();
+            let len = 1 + !settings.is_empty() as usize;
+
+            let mut map = s.serialize_map(Some(len))?;
+            map.serialize_entry("acronym", self.acronym())?;
+
+            if !settings.is_empty() {
+                map.serialize_entry("settings", &Settings(&settings))?;
+            }
+
+            map.end()
+        }
+    }
+
+    impl Serialize for LazerMods {
+        #[inline]
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            s.collect_seq(self.0.iter())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_with_settings() {
+        let mods: LazerMods = "dt(1.3x)HDCl".parse().unwrap();
+
+        let expected = LazerMods(vec![
+            LazerMod::DoubleTime {
+                speed_change: Some(1.3),
+                adjust_pitch: None,
+            },
+            LazerMod::Hidden {
+                only_fade_approach_circles: None,
+            },
+            LazerMod::Classic {
+                no_slider_head_accuracy: None,
+                classic_note_lock: None,
+                always_play_tail_sample: None,
+                fade_hit_circle_early: None,
+                classic_health: None,
+            },
+        ]);
+
+        assert_eq!(mods, expected);
+        assert_eq!(mods.to_string(), "DT(1.3x)HDCL");
+
+        let mods: LazerMods = "DA(approach_rate=9.5,extended_limits=true)XY(a=b)"
+            .parse()
+            .unwrap();
+        assert_eq!(mods.to_string().parse::<LazerMods>().unwrap(), mods);
+        assert!(matches!(mods.get("XY"), Some(LazerMod::Unknown { .. })));
+
+        assert!("HDD".parse::<LazerMods>().is_err());
+        assert!("DT(1.3".parse::<LazerMods>().is_err());
+        assert!("éé".parse::<LazerMods>().is_err());
+        assert!("Hé".parse::<LazerMods>().is_err());
+        assert!("HDé".parse::<LazerMods>().is_err());
+    }
+
+    #[test]
+    fn legacy_roundtrip() {
+        for legacy in GameMods::all() {
+            let mods = LazerMods::from(legacy);
+            assert_eq!(mods.try_legacy(), Some(legacy), "{:?}", legacy);
+        }
+
+        let legacy = GameMods::Hidden | GameMods::Perfect | GameMods::NightCore | GameMods::Key4;
+        assert_eq!(LazerMods::from(legacy).try_legacy(), Some(legacy));
+    }
+
+    #[test]
+    fn deserialize_json() {
+        let json = r#"[
+            {"acronym":"DT","settings":{"speed_change":1.2,"adjust_pitch":true}},
+            {"acronym":"HD"},
+            {"acronym":"HR","settings":[]},
+            {"acronym":"EZ","settings":{"retries":"two"}},
+            "FL"
+        ]"#;
+
+        let mods: LazerMods = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            mods.0[0],
+            LazerMod::DoubleTime {
+                speed_change: Some(1.2),
+                adjust_pitch: Some(true)
+            }
+        );
+        assert!(matches!(mods.0[3], LazerMod::Unknown { .. }));
+        assert_eq!(
+            mods.to_string(),
+            "DT(1.2x,adjust_pitch=true)HDHREZ(retries=two)FL"
+        );
+        assert_eq!(
+            mods.legacy(),
+            GameMods::DoubleTime | GameMods::Hidden | GameMods::HardRock | GameMods::Flashlight
+        );
+
+        let mods: LazerMods = serde_json::from_str("24").unwrap();
+        assert_eq!(
+            mods.try_legacy(),
+            Some(GameMods::Hidden | GameMods::HardRock)
+        );
+    }
+}
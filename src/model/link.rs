@@ -0,0 +1,825 @@
+use super::{
+    beatmap::{Beatmap, BeatmapCompact, Beatmapset, BeatmapsetCompact},
+    forum::ForumTopic,
+    matches::{MatchInfo, OsuMatch},
+    news::NewsPost,
+    score::Score,
+    user::{User, UserCompact},
+    wiki::WikiPage,
+    GameMode,
+};
+use crate::{
+    error::{OsuError, ParsingError},
+    request::{
+        GetBeatmap, GetBeatmapset, GetForumPosts, GetMatch, GetNewsPost, GetScore, GetUser,
+        GetWikiPage, UserId,
+    },
+    Osu,
+};
+
+use std::{fmt, str::FromStr};
+use url::Url;
+
+const BASE_URL: &str = "https://osu.ppy.sh";
+
+/// A link to a page of the osu! website, parsed into the identifiers required to request it.
+///
+/// Links can be parsed from full URLs such as `https://osu.ppy.sh/beatmapsets/1#osu/2`,
+/// URLs without scheme such as `osu.ppy.sh/b/123`, legacy paths of the old website
+/// such as `/u/peppy` or `/mp/123`, as well as `osu://` links of the game client.
+///
+/// Its [`Display`](fmt::Display) implementation writes the canonical URL.
+///
+/// ```
+/// use rosu_v2::prelude::*;
+///
+/// let link: OsuLink = "https://osu.ppy.sh/beatmapsets/1#taiko/75".parse().unwrap();
+///
+/// assert_eq!(
+///     link,
+///     OsuLink::Mapset {
+///         mapset_id: 1,
+///         map_id: Some(75),
+///         mode: Some(GameMode::Taiko),
+///     }
+/// );
+///
+/// assert_eq!(link.to_string(), "https://osu.ppy.sh/beatmapsets/1#taiko/75");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum OsuLink {
+    /// A beatmap, see [`Osu::beatmap`]
+    Map { map_id: u32, mode: Option<GameMode> },
+    /// A beatmapset and optionally one of its beatmaps, see [`Osu::beatmapset`]
+    Mapset {
+        mapset_id: u32,
+        map_id: Option<u32>,
+        mode: Option<GameMode>,
+    },
+    /// A user's profile, see [`Osu::user`]
+    User {
+        user_id: UserId,
+        mode: Option<GameMode>,
+    },
+    /// A score; without mode the id is an osu!lazer score id, see [`Osu::score`]
+    Score {
+        score_id: u64,
+        mode: Option<GameMode>,
+    },
+    /// A multiplayer match, see [`Osu::osu_match`]
+    Match { match_id: u32 },
+    /// A forum topic, see [`Osu::forum_posts`]
+    ForumTopic { topic_id: u64 },
+    /// A wiki page, see [`Osu::wiki`]
+    Wiki {
+        locale: String,
+        path: Option<String>,
+    },
+    /// A news post, see [`Osu::news_post`]
+    News { slug: String },
+}
+
+impl OsuLink {
+    /// The canonical URL of the link.
+    #[inline]
+    pub fn url(&self) -> String {
+        self.to_string()
+    }
+
+    /// The id of the beatmap this link points to, if any.
+    #[inline]
+    pub fn map_id(&self) -> Option<u32> {
+        match self {
+            Self::Map { map_id, .. } => Some(*map_id),
+            Self::Mapset { map_id, .. } => *map_id,
+            _ => None,
+        }
+    }
+
+    /// The mode specified in the link, if any.
+    #[inline]
+    pub fn mode(&self) -> Option<GameMode> {
+        match self {
+            Self::Map { mode, .. }
+            | Self::Mapset { mode, .. }
+            | Self::User { mode, .. }
+            | Self::Score { mode, .. } => *mode,
+            _ => None,
+        }
+    }
+
+    /// Only works if the link points to a beatmap, either
+    /// through [`OsuLink::Map`] or through [`OsuLink::Mapset`] with a map id.
+    #[inline]
+    pub fn get_beatmap<'o>(&self, osu: &'o Osu) -> Option<GetBeatmap<'o>> {
+        self.map_id().map(|map_id| osu.beatmap().map_id(map_id))
+    }
+
+    /// Only works if the link is an [`OsuLink::Mapset`].
+    #[inline]
+    pub fn get_beatmapset<'o>(&self, osu: &'o Osu) -> Option<GetBeatmapset<'o>> {
+        match self {
+            Self::Mapset { mapset_id, .. } => Some(osu.beatmapset(*mapset_id)),
+            _ => None,
+        }
+    }
+
+    /// Only works if the link is an [`OsuLink::User`].
+    #[inline]
+    pub fn get_user<'o>(&self, osu: &'o Osu) -> Option<GetUser<'o>> {
+        match self {
+            Self::User {
+                user_id,
+                mode: Some(mode),
+            } => Some(osu.user(user_id.to_owned()).mode(*mode)),
+            Self::User {
+                user_id,
+                mode: None,
+            } => Some(osu.user(user_id.to_owned())),
+            _ => None,
+        }
+    }
+
+    /// Only works if the link is an [`OsuLink::Score`].
+    #[inline]
+    pub fn get_score<'o>(&self, osu: &'o Osu) -> Option<GetScore<'o>> {
+        match self {
+            Self::Score {
+                score_id,
+                mode: Some(mode),
+            } => Some(osu.score(*score_id).mode(*mode)),
+            Self::Score {
+                score_id,
+                mode: None,
+            } => Some(osu.score(*score_id)),
+            _ => None,
+        }
+    }
+
+    /// Only works if the link is an [`OsuLink::Match`].
+    #[inline]
+    pub fn get_match<'o>(&self, osu: &'o Osu) -> Option<GetMatch<'o>> {
+        match self {
+            Self::Match { match_id } => Some(osu.osu_match(*match_id)),
+            _ => None,
+        }
+    }
+
+    /// Only works if the link is an [`OsuLink::ForumTopic`].
+    #[inline]
+    pub fn get_forum_posts<'o>(&self, osu: &'o Osu) -> Option<GetForumPosts<'o>> {
+        match self {
+            Self::ForumTopic { topic_id } => Some(osu.forum_posts(*topic_id)),
+            _ => None,
+        }
+    }
+
+    /// Only works if the link is an [`OsuLink::Wiki`].
+    #[inline]
+    pub fn get_wiki_page<'o>(&self, osu: &'o Osu) -> Option<GetWikiPage<'o>> {
+        match self {
+            Self::Wiki {
+                locale,
+                path: Some(path),
+            } => Some(osu.wiki(locale.as_str()).page(path.as_str())),
+            Self::Wiki { locale, path: None } => Some(osu.wiki(locale.as_str())),
+            _ => None,
+        }
+    }
+
+    /// Only works if the link is an [`OsuLink::News`].
+    #[inline]
+    pub fn get_news_post<'o>(&self, osu: &'o Osu) -> Option<GetNewsPost<'o>> {
+        match self {
+            Self::News { slug } => Some(osu.news_post(slug.as_str())),
+            _ => None,
+        }
+    }
+
+    /// The link to a score, if it has an id.
+    ///
+    /// Scores with an osu!stable score id are linked through their mode,
+    /// otherwise the link uses the modeless osu!lazer score id.
+    #[inline]
+    pub fn from_score(score: &Score) -> Option<Self> {
+        let legacy_id = score
+            .legacy_score_id
+            .filter(|&score_id| score_id > 0)
+            .or_else(|| score.score_id.filter(|&score_id| score_id > 0));
+
+        match legacy_id {
+            Some(score_id) => Some(Self::Score {
+                score_id,
+                mode: Some(score.mode),
+            }),
+            None => score.id.map(|score_id| Self::Score {
+                score_id,
+                mode: None,
+            }),
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim().trim_start_matches('<').trim_end_matches('>');
+
+        let url = if s.contains("://") {
+            Url::parse(s).ok()?
+        } else if s.starts_with('/') {
+            Url::parse(&format!("{}{}", BASE_URL, s)).ok()?
+        } else {
+            Url::parse(&format!("https://{}", s)).ok()?
+        };
+
+        let path = url.path().trim_matches('/');
+
+        // Legacy links such as `/b/123&m=1` append the query without `?`
+        let (path, legacy_query) = match path.split_once('&') {
+            Some((path, query)) => (path, Some(query)),
+            None => (path, None),
+        };
+
+        let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+
+        let query_mode = || {
+            url.query_pairs()
+                .chain(
+                    legacy_query
+                        .map(|query| url::form_urlencoded::parse(query.as_bytes()))
+                        .into_iter()
+                        .flatten(),
+                )
+                .find(|(key, _)| key == "m" || key == "mode")
+                .and_then(|(_, value)| parse_mode(&value))
+        };
+
+        match url.scheme() {
+            "osu" => {
+                let id = segments.next()?;
+
+                return match url.host_str()? {
+                    "b" => Some(Self::Map {
+                        map_id: id.parse().ok()?,
+                        mode: None,
+                    }),
+                    "s" | "dl" => Some(Self::Mapset {
+                        mapset_id: id.parse().ok()?,
+                        map_id: None,
+                        mode: None,
+                    }),
+                    "mp" => Some(Self::Match {
+                        match_id: id.parse().ok()?,
+                    }),
+                    _ => None,
+                };
+            }
+            "http" | "https" => {}
+            _ => return None,
+        }
+
+        match url.host_str()? {
+            "osu.ppy.sh" | "old.ppy.sh" | "new.ppy.sh" | "lazer.ppy.sh" => {}
+            _ => return None,
+        }
+
+        match segments.next()? {
+            "b" | "beatmaps" => Some(Self::Map {
+                map_id: segments.next()?.parse().ok()?,
+                mode: query_mode(),
+            }),
+            "s" | "beatmapsets" => {
+                let mapset_id = segments.next()?.parse().ok()?;
+
+                // Fragments are of the form `osu/123`, `/123`, or `osu`
+                let (mode, map_id) = match url.fragment() {
+                    Some(fragment) => match fragment.split_once('/') {
+                        Some((mode, map_id)) => (parse_mode(mode), map_id.parse().ok()),
+                        None => (parse_mode(fragment), None),
+                    },
+                    None => (None, None),
+                };
+
+                Some(Self::Mapset {
+                    mapset_id,
+                    map_id,
+                    mode: mode.or_else(query_mode),
+                })
+            }
+            "u" | "users" => {
+                let user = percent_decode(segments.next()?)?;
+
+                let user_id = match user.parse() {
+                    Ok(user_id) => UserId::Id(user_id),
+                    Err(_) => UserId::from(user),
+                };
+
+                let mode = segments.next().and_then(parse_mode).or_else(query_mode);
+
+                Some(Self::User { user_id, mode })
+            }
+            "scores" => {
+                let first = segments.next()?;
+
+                match segments.next() {
+                    Some(score_id) => Some(Self::Score {
+                        score_id: score_id.parse().ok()?,
+                        mode: Some(parse_mode(first)?),
+                    }),
+                    None => Some(Self::Score {
+                        score_id: first.parse().ok()?,
+                        mode: None,
+                    }),
+                }
+            }
+            "mp" => Some(Self::Match {
+                match_id: segments.next()?.parse().ok()?,
+            }),
+            "community" => match (segments.next()?, segments.next()?) {
+                ("matches", match_id) => Some(Self::Match {
+                    match_id: match_id.parse().ok()?,
+                }),
+                ("forums", "topics") => Some(Self::ForumTopic {
+                    topic_id: segments.next()?.parse().ok()?,
+                }),
+                _ => None,
+            },
+            "forum" => match segments.next()? {
+                "t" => Some(Self::ForumTopic {
+                    topic_id: segments.next()?.parse().ok()?,
+                }),
+                _ => None,
+            },
+            "wiki" => {
+                let mut segments = segments.peekable();
+
+                let locale = match segments.peek() {
+                    Some(segment) if is_locale(segment) => {
+                        segments.next().map(str::to_ascii_lowercase)
+                    }
+                    _ => None,
+                };
+
+                let path = segments
+                    .map(percent_decode)
+                    .collect::<Option<Vec<_>>>()?
+                    .join("/");
+
+                Some(Self::Wiki {
+                    locale: locale.unwrap_or_else(|| "en".to_owned()),
+                    path: Some(path).filter(|path| !path.is_empty()),
+                })
+            }
+            "home" => match segments.next()? {
+                "news" => Some(Self::News {
+                    slug: percent_decode(segments.next()?)?,
+                }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for OsuLink {
+    type Err = OsuError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| ParsingError::OsuLink(s.to_owned()).into())
+    }
+}
+
+impl fmt::Display for OsuLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(BASE_URL)?;
+
+        match self {
+            Self::Map { map_id, mode } => {
+                write!(f, "/beatmaps/{}", map_id)?;
+
+                match mode {
+                    Some(mode) => write!(f, "?mode={}", mode),
+                    None => Ok(()),
+                }
+            }
+            Self::Mapset {
+                mapset_id,
+                map_id,
+                mode,
+            } => {
+                write!(f, "/beatmapsets/{}", mapset_id)?;
+
+                match (mode, map_id) {
+                    (Some(mode), Some(map_id)) => write!(f, "#{}/{}", mode, map_id),
+                    (None, Some(map_id)) => write!(f, "#/{}", map_id),
+                    (Some(mode), None) => write!(f, "#{}", mode),
+                    (None, None) => Ok(()),
+                }
+            }
+            Self::User { user_id, mode } => {
+                f.write_str("/users/")?;
+
+                match user_id {
+                    UserId::Id(user_id) => write!(f, "{}", user_id)?,
+                    UserId::Name(name) => f.write_str(&name.replace(' ', "%20"))?,
+                }
+
+                match mode {
+                    Some(mode) => write!(f, "/{}", mode),
+                    None => Ok(()),
+                }
+            }
+            Self::Score {
+                score_id,
+                mode: Some(mode),
+            } => write!(f, "/scores/{}/{}", mode, score_id),
+            Self::Score {
+                score_id,
+                mode: None,
+            } => write!(f, "/scores/{}", score_id),
+            Self::Match { match_id } => write!(f, "/community/matches/{}", match_id),
+            Self::ForumTopic { topic_id } => write!(f, "/community/forums/topics/{}", topic_id),
+            Self::Wiki {
+                locale,
+                path: Some(path),
+            } => write!(f, "/wiki/{}/{}", locale, path.replace(' ', "_")),
+            Self::Wiki { locale, path: None } => write!(f, "/wiki/{}", locale),
+            Self::News { slug } => write!(f, "/home/news/{}", slug),
+        }
+    }
+}
+
+impl From<&Beatmap> for OsuLink {
+    #[inline]
+    fn from(map: &Beatmap) -> Self {
+        Self::Mapset {
+            mapset_id: map.mapset_id,
+            map_id: Some(map.map_id),
+            mode: Some(map.mode),
+        }
+    }
+}
+
+impl From<&BeatmapCompact> for OsuLink {
+    #[inline]
+    fn from(map: &BeatmapCompact) -> Self {
+        match map.mapset {
+            Some(ref mapset) => Self::Mapset {
+                mapset_id: mapset.mapset_id,
+                map_id: Some(map.map_id),
+                mode: Some(map.mode),
+            },
+            None => Self::Map {
+                map_id: map.map_id,
+                mode: Some(map.mode),
+            },
+        }
+    }
+}
+
+impl From<&Beatmapset> for OsuLink {
+    #[inline]
+    fn from(mapset: &Beatmapset) -> Self {
+        Self::Mapset {
+            mapset_id: mapset.mapset_id,
+            map_id: None,
+            mode: None,
+        }
+    }
+}
+
+impl From<&BeatmapsetCompact> for OsuLink {
+    #[inline]
+    fn from(mapset: &BeatmapsetCompact) -> Self {
+        Self::Mapset {
+            mapset_id: mapset.mapset_id,
+            map_id: None,
+            mode: None,
+        }
+    }
+}
+
+impl From<&User> for OsuLink {
+    #[inline]
+    fn from(user: &User) -> Self {
+        Self::User {
+            user_id: UserId::Id(user.user_id),
+            mode: Some(user.mode),
+        }
+    }
+}
+
+impl From<&UserCompact> for OsuLink {
+    #[inline]
+    fn from(user: &UserCompact) -> Self {
+        Self::User {
+            user_id: UserId::Id(user.user_id),
+            mode: None,
+        }
+    }
+}
+
+impl From<&OsuMatch> for OsuLink {
+    #[inline]
+    fn from(osu_match: &OsuMatch) -> Self {
+        Self::Match {
+            match_id: osu_match.match_id,
+        }
+    }
+}
+
+impl From<&MatchInfo> for OsuLink {
+    #[inline]
+    fn from(info: &MatchInfo) -> Self {
+        Self::Match {
+            match_id: info.match_id,
+        }
+    }
+}
+
+impl From<&ForumTopic> for OsuLink {
+    #[inline]
+    fn from(topic: &ForumTopic) -> Self {
+        Self::ForumTopic {
+            topic_id: topic.topic_id,
+        }
+    }
+}
+
+impl From<&WikiPage> for OsuLink {
+    #[inline]
+    fn from(page: &WikiPage) -> Self {
+        Self::Wiki {
+            locale: page.locale.to_owned(),
+            path: Some(page.path.to_owned()),
+        }
+    }
+}
+
+impl From<&NewsPost> for OsuLink {
+    #[inline]
+    fn from(post: &NewsPost) -> Self {
+        Self::News {
+            slug: post.slug.to_owned(),
+        }
+    }
+}
+
+fn parse_mode(s: &str) -> Option<GameMode> {
+    match s {
+        "0" | "osu" => Some(GameMode::Osu),
+        "1" | "taiko" => Some(GameMode::Taiko),
+        "2" | "fruits" | "catch" | "ctb" => Some(GameMode::Catch),
+        "3" | "mania" => Some(GameMode::Mania),
+        _ => None,
+    }
+}
+
+/// Locales are either two letters such as `en` or of the form `zh-tw`
+fn is_locale(s: &str) -> bool {
+    let bytes = s.as_bytes();
+
+    match bytes.len() {
+        2 => bytes.iter().all(u8::is_ascii_alphabetic),
+        5 => {
+            bytes[2] == b'-'
+                && bytes[..2].iter().all(u8::is_ascii_alphabetic)
+                && bytes[3..].iter().all(u8::is_ascii_alphabetic)
+        }
+        _ => false,
+    }
+}
+
+fn percent_decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hi = (iter.next()? as char).to_digit(16)?;
+            let lo = (iter.next()? as char).to_digit(16)?;
+            bytes.push((hi * 16 + lo) as u8);
+        } else {
+            bytes.push(byte);
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> OsuLink {
+        s.parse()
+            .unwrap_or_else(|_| panic!("failed to parse `{}`", s))
+    }
+
+    #[test]
+    fn maps_and_mapsets() {
+        assert_eq!(
+            parse("osu.ppy.sh/b/123"),
+            OsuLink::Map {
+                map_id: 123,
+                mode: None
+            }
+        );
+        assert_eq!(
+            parse("https://old.ppy.sh/b/123&m=1"),
+            OsuLink::Map {
+                map_id: 123,
+                mode: Some(GameMode::Taiko)
+            }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/beatmaps/123?mode=mania"),
+            OsuLink::Map {
+                map_id: 123,
+                mode: Some(GameMode::Mania)
+            }
+        );
+        assert_eq!(
+            parse("<https://osu.ppy.sh/beatmapsets/1#osu/2>"),
+            OsuLink::Mapset {
+                mapset_id: 1,
+                map_id: Some(2),
+                mode: Some(GameMode::Osu)
+            }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/beatmapsets/1#/2"),
+            OsuLink::Mapset {
+                mapset_id: 1,
+                map_id: Some(2),
+                mode: None
+            }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/s/1"),
+            OsuLink::Mapset {
+                mapset_id: 1,
+                map_id: None,
+                mode: None
+            }
+        );
+        assert_eq!(
+            parse("osu://b/123"),
+            OsuLink::Map {
+                map_id: 123,
+                mode: None
+            }
+        );
+        assert_eq!(parse("osu://mp/5"), OsuLink::Match { match_id: 5 });
+
+        assert!("https://osu.ppy.sh/beatmaps/abc"
+            .parse::<OsuLink>()
+            .is_err());
+        assert!("https://example.com/b/123".parse::<OsuLink>().is_err());
+    }
+
+    #[test]
+    fn users_and_scores() {
+        assert_eq!(
+            parse("https://osu.ppy.sh/users/peppy/taiko"),
+            OsuLink::User {
+                user_id: UserId::from("peppy"),
+                mode: Some(GameMode::Taiko)
+            }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/u/some%20name"),
+            OsuLink::User {
+                user_id: UserId::from("some name"),
+                mode: None
+            }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/users/2"),
+            OsuLink::User {
+                user_id: UserId::Id(2),
+                mode: None
+            }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/scores/fruits/123"),
+            OsuLink::Score {
+                score_id: 123,
+                mode: Some(GameMode::Catch)
+            }
+        );
+        assert_eq!(
+            parse("/scores/123"),
+            OsuLink::Score {
+                score_id: 123,
+                mode: None
+            }
+        );
+    }
+
+    #[test]
+    fn community_wiki_and_news() {
+        assert_eq!(
+            parse("https://osu.ppy.sh/community/matches/1"),
+            OsuLink::Match { match_id: 1 }
+        );
+        assert_eq!(parse("osu.ppy.sh/mp/1"), OsuLink::Match { match_id: 1 });
+        assert_eq!(
+            parse("https://osu.ppy.sh/community/forums/topics/123?n=1"),
+            OsuLink::ForumTopic { topic_id: 123 }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/wiki/de/Hit_object"),
+            OsuLink::Wiki {
+                locale: "de".to_owned(),
+                path: Some("Hit_object".to_owned())
+            }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/wiki/Game_mode/osu!"),
+            OsuLink::Wiki {
+                locale: "en".to_owned(),
+                path: Some("Game_mode/osu!".to_owned())
+            }
+        );
+        assert_eq!(
+            parse("https://osu.ppy.sh/home/news/2021-01-01-some-news"),
+            OsuLink::News {
+                slug: "2021-01-01-some-news".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn canonical_roundtrip() {
+        let links = [
+            "https://osu.ppy.sh/beatmaps/1?mode=taiko",
+            "https://osu.ppy.sh/beatmapsets/1#mania/2",
+            "https://osu.ppy.sh/users/some%20name/fruits",
+            "https://osu.ppy.sh/scores/osu/123",
+            "https://osu.ppy.sh/scores/123",
+            "https://osu.ppy.sh/community/matches/1",
+            "https://osu.ppy.sh/community/forums/topics/1",
+            "https://osu.ppy.sh/wiki/zh-tw/Hit_object",
+            "https://osu.ppy.sh/home/news/2021-01-01-some-news",
+        ];
+
+        for link in links.iter() {
+            assert_eq!(parse(link).to_string(), *link);
+        }
+    }
+
+    #[test]
+    fn score_links() {
+        let score = |legacy_score_id: Option<u64>, best_id: Option<u64>, id: Option<u64>| {
+            let json = serde_json::json!({
+                "accuracy": 1.0,
+                "ended_at": "2022-01-01T00:00:00Z",
+                "passed": true,
+                "rank": "X",
+                "beatmap_id": 1,
+                "max_combo": 100,
+                "id": id,
+                "legacy_score_id": legacy_score_id,
+                "ruleset_id": 1,
+                "mods": [],
+                "legacy_perfect": true,
+                "has_replay": false,
+                "total_score": 1000000,
+                "best_id": best_id,
+                "statistics": {},
+                "user_id": 2
+            });
+
+            serde_json::from_value::<Score>(json).unwrap()
+        };
+
+        let legacy = |score_id| OsuLink::Score {
+            score_id,
+            mode: Some(GameMode::Taiko),
+        };
+
+        let lazer = |score_id| OsuLink::Score {
+            score_id,
+            mode: None,
+        };
+
+        assert_eq!(
+            OsuLink::from_score(&score(Some(456), Some(123), Some(789))),
+            Some(legacy(456))
+        );
+        assert_eq!(
+            OsuLink::from_score(&score(Some(0), Some(123), Some(789))),
+            Some(legacy(123))
+        );
+        assert_eq!(
+            OsuLink::from_score(&score(None, None, Some(789))),
+            Some(lazer(789))
+        );
+        assert_eq!(
+            OsuLink::from_score(&score(Some(0), None, Some(789))),
+            Some(lazer(789))
+        );
+        assert_eq!(OsuLink::from_score(&score(None, None, None)), None);
+    }
+}
@@ -0,0 +1,417 @@
+use super::matches_::{MatchEvent, MatchGame, OsuMatch};
+
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+/// The state of a multiplayer lobby, reconstructed by replaying [`MatchEvent`]s.
+///
+/// Note that the API only sends the most recent events unless the full match was requested,
+/// see [`Osu::osu_match_full`](crate::Osu::osu_match_full). When replaying an incomplete
+/// event log, players that submit a score without a known join event are considered to
+/// have joined at the start of that game.
+///
+/// # Example
+/// ```no_run
+/// use rosu_v2::{model::matches::MatchState, Osu};
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let osu_match = osu.osu_match_full(123_456_789).await?;
+/// let state = osu_match.state();
+///
+/// for (host, picks) in state.picks_by_host() {
+///     println!("{} picked {} maps", host, picks.len());
+/// }
+///
+/// if let Some(game) = state.games.first() {
+///     println!("Lobby during the first game: {:?}", game.players);
+/// }
+/// # Ok::<_, rosu_v2::error::OsuError>(()) };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MatchState {
+    /// Id of the last applied event
+    pub event_id: Option<u64>,
+    /// Timestamp of the last applied event
+    pub timestamp: Option<OffsetDateTime>,
+    /// User ids of the players currently in the lobby, in the order they joined
+    pub players: Vec<u32>,
+    /// User id of the current host
+    pub host: Option<u32>,
+    /// All games played so far, ordered by their event id
+    pub games: Vec<MatchGameRecord>,
+    /// Maps user ids to the intervals in which they were in the lobby
+    pub participations: HashMap<u32, Vec<Participation>>,
+    /// Whether the match was disbanded
+    pub disbanded: bool,
+}
+
+/// A game as seen by [`MatchState`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchGameRecord {
+    /// Id of the [`MatchEvent::Game`] event
+    pub event_id: u64,
+    pub game_id: u64,
+    /// `None` if the map was deleted
+    pub map_id: Option<u32>,
+    /// The host when the game started i.e. the player that picked the map
+    pub host: Option<u32>,
+    /// User ids of the players in the lobby when the game started
+    pub players: Vec<u32>,
+    /// Whether the game received its end time
+    pub finished: bool,
+}
+
+/// An interval in which a player was in the lobby.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Participation {
+    /// Id of the event that started the participation
+    pub start_event_id: u64,
+    pub start: OffsetDateTime,
+    /// Id of the event that ended the participation, `None` if the player is still in the lobby
+    pub end_event_id: Option<u64>,
+    pub end: Option<OffsetDateTime>,
+    /// Whether the player was kicked
+    pub kicked: bool,
+}
+
+impl Participation {
+    /// Check whether the player was in the lobby during the given event.
+    ///
+    /// Both the event that started and the event that ended the participation are included.
+    #[inline]
+    pub fn contains(&self, event_id: u64) -> bool {
+        self.start_event_id <= event_id && !matches!(self.end_event_id, Some(end) if end < event_id)
+    }
+}
+
+impl MatchState {
+    /// Create an empty state i.e. the state before any event.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replay all given events.
+    pub fn replay<'e, I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = &'e MatchEvent>,
+    {
+        let mut state = Self::new();
+        state.extend(events);
+
+        state
+    }
+
+    /// Replay all given events up to and including the event with the given id.
+    ///
+    /// The events are expected to be ordered by their id.
+    pub fn replay_until<'e, I>(events: I, event_id: u64) -> Self
+    where
+        I: IntoIterator<Item = &'e MatchEvent>,
+    {
+        Self::replay(
+            events
+                .into_iter()
+                .take_while(|event| event.event_id() <= event_id),
+        )
+    }
+
+    /// Apply all given events to the state.
+    pub fn extend<'e, I>(&mut self, events: I)
+    where
+        I: IntoIterator<Item = &'e MatchEvent>,
+    {
+        for event in events {
+            self.apply(event);
+        }
+    }
+
+    /// Apply a single event to the state.
+    ///
+    /// Applying a game event with the id of an already known game updates that game,
+    /// e.g. when an in-progress game finished.
+    pub fn apply(&mut self, event: &MatchEvent) {
+        let event_id = event.event_id();
+        let timestamp = event.timestamp();
+
+        match event {
+            MatchEvent::Create { user_id, .. } => {
+                if let Some(user_id) = *user_id {
+                    self.join(user_id, event_id, timestamp);
+                    self.host = Some(user_id);
+                }
+            }
+            MatchEvent::Disbanded { .. } => {
+                for user_id in std::mem::take(&mut self.players) {
+                    self.end_participation(user_id, event_id, timestamp, false);
+                }
+
+                self.host = None;
+                self.disbanded = true;
+            }
+            MatchEvent::Game { game, .. } => self.apply_game(event_id, timestamp, game),
+            MatchEvent::HostChanged { user_id, .. } => {
+                self.join(*user_id, event_id, timestamp);
+                self.host = Some(*user_id);
+            }
+            MatchEvent::Joined { user_id, .. } => self.join(*user_id, event_id, timestamp),
+            MatchEvent::Kicked { user_id, .. } => self.leave(*user_id, event_id, timestamp, true),
+            MatchEvent::Left { user_id, .. } => self.leave(*user_id, event_id, timestamp, false),
+        }
+
+        self.event_id = Some(event_id);
+        self.timestamp = Some(timestamp);
+    }
+
+    /// Check whether the given user is currently in the lobby.
+    #[inline]
+    pub fn is_present(&self, user_id: u32) -> bool {
+        self.players.contains(&user_id)
+    }
+
+    /// Get the user ids of the players that were in the lobby during the given event.
+    pub fn players_at(&self, event_id: u64) -> Vec<u32> {
+        let mut players: Vec<_> = self
+            .participations
+            .iter()
+            .filter_map(|(user_id, intervals)| {
+                intervals
+                    .iter()
+                    .find(|p| p.contains(event_id))
+                    .map(|p| (p.start_event_id, *user_id))
+            })
+            .collect();
+
+        players.sort_unstable();
+
+        players.into_iter().map(|(_, user_id)| user_id).collect()
+    }
+
+    /// Get the game with the given index, starting at 0, e.g. the game played as N-th map.
+    #[inline]
+    pub fn game(&self, idx: usize) -> Option<&MatchGameRecord> {
+        self.games.get(idx)
+    }
+
+    /// Get the game with the given game id.
+    #[inline]
+    pub fn game_by_id(&self, game_id: u64) -> Option<&MatchGameRecord> {
+        self.games.iter().find(|game| game.game_id == game_id)
+    }
+
+    /// Get the user ids of the players that were in the lobby when the game
+    /// with the given index, starting at 0, started.
+    #[inline]
+    pub fn players_during_game(&self, idx: usize) -> Option<&[u32]> {
+        self.game(idx).map(|game| game.players.as_slice())
+    }
+
+    /// Iterate over all games that were started while the given user was host.
+    #[inline]
+    pub fn picks_by(&self, user_id: u32) -> impl Iterator<Item = &MatchGameRecord> {
+        self.games
+            .iter()
+            .filter(move |game| game.host == Some(user_id))
+    }
+
+    /// Group all games by the host at the time they started.
+    ///
+    /// Games without known host are not included.
+    pub fn picks_by_host(&self) -> HashMap<u32, Vec<&MatchGameRecord>> {
+        let mut picks: HashMap<_, Vec<_>> = HashMap::new();
+
+        for game in self.games.iter() {
+            if let Some(host) = game.host {
+                picks.entry(host).or_default().push(game);
+            }
+        }
+
+        picks
+    }
+
+    fn apply_game(&mut self, event_id: u64, timestamp: OffsetDateTime, game: &MatchGame) {
+        let scorers: Vec<_> = game
+            .scores
+            .iter()
+            .map(|score| score.user_id)
+            .filter(|user_id| !self.is_present(*user_id))
+            .collect();
+
+        for user_id in scorers {
+            self.join(user_id, event_id, timestamp);
+        }
+
+        let record = MatchGameRecord {
+            event_id,
+            game_id: game.game_id,
+            map_id: game.map.as_ref().map(|map| map.map_id),
+            host: self.host,
+            players: self.players.clone(),
+            finished: game.end_time.is_some(),
+        };
+
+        match self.games.iter_mut().find(|game| game.event_id == event_id) {
+            Some(existing) => {
+                // Keep the host and players from when the game started
+                existing.map_id = record.map_id;
+                existing.finished = record.finished;
+
+                for user_id in record.players {
+                    if !existing.players.contains(&user_id) {
+                        existing.players.push(user_id);
+                    }
+                }
+            }
+            None => self.games.push(record),
+        }
+    }
+
+    fn join(&mut self, user_id: u32, event_id: u64, timestamp: OffsetDateTime) {
+        if self.is_present(user_id) {
+            return;
+        }
+
+        self.players.push(user_id);
+
+        let participation = Participation {
+            start_event_id: event_id,
+            start: timestamp,
+            end_event_id: None,
+            end: None,
+            kicked: false,
+        };
+
+        self.participations
+            .entry(user_id)
+            .or_default()
+            .push(participation);
+    }
+
+    fn leave(&mut self, user_id: u32, event_id: u64, timestamp: OffsetDateTime, kicked: bool) {
+        self.players.retain(|&id| id != user_id);
+
+        if self.host == Some(user_id) {
+            self.host = None;
+        }
+
+        self.end_participation(user_id, event_id, timestamp, kicked);
+    }
+
+    fn end_participation(
+        &mut self,
+        user_id: u32,
+        event_id: u64,
+        timestamp: OffsetDateTime,
+        kicked: bool,
+    ) {
+        let open = self
+            .participations
+            .get_mut(&user_id)
+            .and_then(|intervals| intervals.last_mut())
+            .filter(|participation| participation.end_event_id.is_none());
+
+        if let Some(participation) = open {
+            participation.end_event_id = Some(event_id);
+            participation.end = Some(timestamp);
+            participation.kicked = kicked;
+        }
+    }
+}
+
+impl OsuMatch {
+    /// Replay all events of the match to reconstruct its current [`MatchState`].
+    #[inline]
+    pub fn state(&self) -> MatchState {
+        MatchState::replay(&self.events)
+    }
+
+    /// Replay the events of the match up to and including the event with the given id.
+    #[inline]
+    pub fn state_at(&self, event_id: u64) -> MatchState {
+        MatchState::replay_until(&self.events, event_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{matches::Team, GameMods},
+        tournament::fixtures,
+    };
+
+    fn game_event(event_id: u64, game_id: u64, scorers: &[u32]) -> MatchEvent {
+        let scores = scorers
+            .iter()
+            .map(|&user_id| fixtures::score(user_id, Team::None, 1_000_000, GameMods::NoMod))
+            .collect();
+
+        MatchEvent::Game {
+            event_id,
+            game: Box::new(MatchGame {
+                game_id,
+                ..fixtures::game(1, scores)
+            }),
+            match_name: String::new(),
+            timestamp: OffsetDateTime::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn replay_events() {
+        let timestamp = OffsetDateTime::UNIX_EPOCH;
+
+        let events = vec![
+            MatchEvent::Create {
+                event_id: 1,
+                timestamp,
+                user_id: Some(10),
+            },
+            MatchEvent::Joined {
+                event_id: 2,
+                timestamp,
+                user_id: 20,
+            },
+            game_event(3, 100, &[10, 20]),
+            MatchEvent::HostChanged {
+                event_id: 4,
+                timestamp,
+                user_id: 20,
+            },
+            MatchEvent::Left {
+                event_id: 5,
+                timestamp,
+                user_id: 10,
+            },
+            // player 30 never joined according to the log
+            game_event(6, 101, &[20, 30]),
+            MatchEvent::Kicked {
+                event_id: 7,
+                timestamp,
+                user_id: 30,
+            },
+            MatchEvent::Disbanded {
+                event_id: 8,
+                timestamp,
+            },
+        ];
+
+        let state = MatchState::replay(&events);
+
+        assert!(state.disbanded);
+        assert!(state.players.is_empty());
+        assert_eq!(state.games.len(), 2);
+        assert_eq!(state.players_during_game(0), Some([10, 20].as_ref()));
+        assert_eq!(state.players_during_game(1), Some([20, 30].as_ref()));
+        assert_eq!(state.picks_by(10).count(), 1);
+        assert_eq!(state.picks_by(20).next().unwrap().game_id, 101);
+        assert_eq!(state.players_at(5), [10, 20]);
+        assert_eq!(state.players_at(6), [20, 30]);
+        assert!(state.participations[&30][0].kicked);
+
+        let partial = MatchState::replay_until(&events, 4);
+        assert_eq!(partial.host, Some(20));
+        assert_eq!(partial.players, [10, 20]);
+    }
+}
@@ -11,7 +11,7 @@ use serde::{
     de::{Deserializer, Error, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor},
     Deserialize,
 };
-use std::{collections::HashMap, fmt, slice::Iter, vec::Drain};
+use std::{collections::HashMap, fmt, mem, slice::Iter, vec::Drain};
 use time::OffsetDateTime;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -299,6 +299,22 @@ impl<'de> Deserialize<'de> for MatchEvent {
     }
 }
 
+/// An update of a live match as yielded by [`MatchStream`](crate::request::MatchStream).
+#[derive(Clone, Debug, PartialEq)]
+pub enum MatchUpdate {
+    /// A new event occured, including games that just started
+    Event(MatchEvent),
+    /// A game that was in progress received new data.
+    ///
+    /// Once the game's `end_time` is set, its scores are final and
+    /// no further updates for the game will be yielded.
+    Game {
+        /// The id of the [`MatchEvent::Game`] containing the game
+        event_id: u64,
+        game: Box<MatchGame>,
+    },
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
@@ -814,7 +830,7 @@ impl OsuMatch {
     pub fn has_previous(&self) -> bool {
         self.events
             .first()
-            .map_or(false, |event| self.first_event_id != event.event_id())
+            .is_some_and(|event| self.first_event_id != event.event_id())
     }
 
     /// Get the [`OsuMatch`] containing only data before some event id.
@@ -836,6 +852,64 @@ impl OsuMatch {
 
         Some(previous)
     }
+
+    /// Merge the events and users of another [`OsuMatch`] of the same match into this one.
+    ///
+    /// Events are sorted by their id and duplicates are removed.
+    /// If both matches contain an event or user with the same id, the one of the match
+    /// with the more recent `latest_event_id` is kept, e.g. an updated in-progress game.
+    pub fn merge(&mut self, other: OsuMatch) {
+        let OsuMatch {
+            current_game_id,
+            end_time,
+            events,
+            first_event_id,
+            latest_event_id,
+            match_id: _,
+            name,
+            start_time,
+            users,
+        } = other;
+
+        let other_is_newer = latest_event_id > self.latest_event_id;
+
+        let mut merged = if other_is_newer {
+            let mut merged = events;
+            merged.append(&mut self.events);
+
+            merged
+        } else {
+            let mut merged = mem::take(&mut self.events);
+            merged.extend(events);
+
+            merged
+        };
+
+        // Stable sort so that the preferred event comes first among duplicates
+        merged.sort_by_key(MatchEvent::event_id);
+        merged.dedup_by_key(|event| event.event_id());
+        self.events = merged;
+
+        if other_is_newer {
+            self.current_game_id = current_game_id;
+            self.latest_event_id = latest_event_id;
+            self.name = name;
+            self.end_time = end_time.or(self.end_time);
+        } else {
+            self.end_time = self.end_time.or(end_time);
+        }
+
+        self.first_event_id = self.first_event_id.min(first_event_id);
+        self.start_time = self.start_time.min(start_time);
+
+        if other_is_newer {
+            self.users.extend(users);
+        } else {
+            for (user_id, user) in users {
+                self.users.entry(user_id).or_insert(user);
+            }
+        }
+    }
 }
 
 struct OsuMatchVisitor;
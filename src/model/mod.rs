@@ -158,24 +158,33 @@ macro_rules! def_enum {
     }
 }
 
+mod beatmap_attributes;
 mod cursor;
 mod grade;
+mod lazer_mods;
+mod link;
+mod match_state;
 mod mode;
 mod mods;
+mod now_playing;
 mod serde_;
+mod user_history;
 
 #[cfg(feature = "rkyv")]
 mod rkyv_impls;
 
 pub(crate) mod beatmap_;
+pub(crate) mod changelog_;
 pub(crate) mod comments_;
 pub(crate) mod forum_;
 pub(crate) mod kudosu_;
 pub(crate) mod matches_;
 pub(crate) mod news_;
+pub(crate) mod notification_;
 pub(crate) mod ranking_;
 pub(crate) mod recent_event_;
 pub(crate) mod score_;
+pub(crate) mod search_;
 pub(crate) mod seasonal_backgrounds_;
 pub(crate) mod user_;
 pub(crate) mod wiki_;
@@ -191,11 +200,23 @@ pub mod beatmap {
         BeatmapsetSearchSort, BeatmapsetVote, FailTimes, GameModeAttributes, Genre, Language,
         MostPlayedMap, RankStatus,
     };
+
+    pub use super::beatmap_attributes::{BeatmapAttributes, HitWindows};
+}
+
+/// Changelog related types
+pub mod changelog {
+    pub use super::changelog_::{
+        Changelog, ChangelogBuild, ChangelogEntry, ChangelogEntryType, ChangelogSearch,
+        ChangelogVersions, GithubUser, UpdateStream,
+    };
 }
 
 /// Comment related types
 pub mod comments {
-    pub use super::comments_::{Comment, CommentBundle, CommentSort, CommentableMeta};
+    pub use super::comments_::{
+        Comment, CommentBundle, CommentSort, CommentableMeta, CommentableType,
+    };
 }
 
 /// Forum post related types
@@ -212,26 +233,37 @@ pub mod kudosu {
 pub mod matches {
     pub use super::matches_::{
         MatchEvent, MatchGame, MatchGameDrain, MatchGameIter, MatchInfo, MatchList,
-        MatchListParams, MatchScore, OsuMatch, ScoringType, Team, TeamType,
+        MatchListParams, MatchScore, MatchUpdate, OsuMatch, ScoringType, Team, TeamType,
     };
+
+    pub use super::match_state::{MatchGameRecord, MatchState, Participation};
 }
 
 /// News related types
 pub mod news {
-    pub use super::news_::{News, NewsPost, NewsSearch, NewsSidebar};
+    pub use super::news_::{News, NewsNavigation, NewsPost, NewsSearch, NewsSidebar};
+}
+
+/// Notification related types
+pub mod notification {
+    pub use super::notification_::{
+        BeatmapOwnerChangeDetails, BeatmapsetDiscussionDetails, BeatmapsetDiscussionEvent,
+        ChannelMessageDetails, CommentNotificationDetails, ForumTopicReplyDetails, Notification,
+        NotificationCategory, NotificationKind, Notifications, UserAchievementDetails,
+    };
 }
 
 /// Ranking related types
 pub mod ranking {
     pub use super::ranking_::{
-        ChartRankings, CountryRanking, CountryRankings, Rankings, Spotlight,
+        ChartRankings, CountryRanking, CountryRankings, RankEstimate, Rankings, Spotlight,
     };
 }
 
 /// User event related types
 pub mod recent_event {
     pub use super::recent_event_::{
-        EventBeatmap, EventBeatmapset, EventType, EventUser, RecentEvent,
+        EventBeatmap, EventBeatmapset, EventType, EventUser, Events, RecentEvent,
     };
 }
 
@@ -240,6 +272,11 @@ pub mod score {
     pub use super::score_::{BeatmapUserScore, Score, ScoreStatistics, ScoreWeight};
 }
 
+/// Search related types
+pub mod search {
+    pub use super::search_::{SearchResult, UserSearchResult, WikiSearchResult};
+}
+
 /// Seasonal background related types
 pub mod seasonal_backgrounds {
     pub use super::seasonal_backgrounds_::{SeasonalBackground, SeasonalBackgrounds};
@@ -252,6 +289,8 @@ pub mod user {
         MonthlyCount, Playstyle, ProfileBanner, ProfilePage, User, UserCompact, UserCover,
         UserHighestRank, UserKudosu, UserLevel, UserPage, UserStatistics, Username,
     };
+
+    pub use super::user_history::{RankHistory, RankHistoryEntry, RankStreak};
 }
 
 /// Wiki related types
@@ -279,9 +318,15 @@ pub mod rkyv {
         FailTimesResolver, GameModeAttributesResolver, MostPlayedMapResolver, RankStatusResolver,
     };
 
+    pub use super::changelog_::{
+        ArchivedChangelogEntry, ArchivedChangelogSearch, ArchivedGithubUser,
+        ChangelogEntryResolver, ChangelogEntryTypeResolver, ChangelogSearchResolver,
+        GithubUserResolver,
+    };
+
     pub use super::comments_::{
         ArchivedComment, ArchivedCommentableMeta, CommentResolver, CommentSortResolver,
-        CommentableMetaResolver,
+        CommentableMetaResolver, CommentableTypeResolver,
     };
 
     pub use super::forum_::{
@@ -299,7 +344,19 @@ pub mod rkyv {
     pub use super::mode::GameModeResolver;
 
     pub use super::news_::{
-        ArchivedNewsPost, ArchivedNewsSidebar, NewsPostResolver, NewsSidebarResolver,
+        ArchivedNewsNavigation, ArchivedNewsPost, ArchivedNewsSidebar, NewsNavigationResolver,
+        NewsPostResolver, NewsSidebarResolver,
+    };
+
+    pub use super::notification_::{
+        ArchivedBeatmapOwnerChangeDetails, ArchivedBeatmapsetDiscussionDetails,
+        ArchivedChannelMessageDetails, ArchivedCommentNotificationDetails,
+        ArchivedForumTopicReplyDetails, ArchivedNotification, ArchivedNotificationKind,
+        ArchivedNotifications, ArchivedUserAchievementDetails, BeatmapOwnerChangeDetailsResolver,
+        BeatmapsetDiscussionDetailsResolver, BeatmapsetDiscussionEventResolver,
+        ChannelMessageDetailsResolver, CommentNotificationDetailsResolver,
+        ForumTopicReplyDetailsResolver, NotificationCategoryResolver, NotificationKindResolver,
+        NotificationResolver, NotificationsResolver, UserAchievementDetailsResolver,
     };
 
     pub use super::ranking_::{
@@ -310,8 +367,8 @@ pub mod rkyv {
 
     pub use super::recent_event_::{
         ArchivedEventBeatmap, ArchivedEventBeatmapset, ArchivedEventType, ArchivedEventUser,
-        ArchivedRecentEvent, EventBeatmapResolver, EventBeatmapsetResolver, EventTypeResolver,
-        EventUserResolver, RecentEventResolver,
+        ArchivedEvents, ArchivedRecentEvent, EventBeatmapResolver, EventBeatmapsetResolver,
+        EventTypeResolver, EventUserResolver, EventsResolver, RecentEventResolver,
     };
 
     pub use super::score_::{
@@ -319,6 +376,11 @@ pub mod rkyv {
         ScoreStatisticsResolver, ScoreWeightResolver,
     };
 
+    pub use super::search_::{
+        ArchivedSearchResult, ArchivedUserSearchResult, ArchivedWikiSearchResult,
+        SearchResultResolver, UserSearchResultResolver, WikiSearchResultResolver,
+    };
+
     pub use super::seasonal_backgrounds_::{
         ArchivedSeasonalBackground, ArchivedSeasonalBackgrounds, SeasonalBackgroundResolver,
         SeasonalBackgroundsResolver,
@@ -340,8 +402,11 @@ pub mod rkyv {
 
 pub use cursor::Cursor;
 pub use grade::Grade;
+pub use lazer_mods::{LazerMod, LazerMods, ModSetting, ModSettingValue};
+pub use link::OsuLink;
 pub use mode::GameMode;
-pub use mods::GameMods;
+pub use mods::{GameMods, ModIncompatibility};
+pub use now_playing::{NowPlaying, NowPlayingStatus};
 
 use std::marker::PhantomData;
 
@@ -271,6 +271,256 @@ impl GameMods {
             1.0
         }
     }
+
+    /// Returns all reasons why the mods are not a valid combination for the given mode.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_v2::model::{GameMode, GameMods, ModIncompatibility};
+    ///
+    /// let mods = GameMods::Easy | GameMods::HardRock | GameMods::Key4;
+    /// let incompatibilities = mods.incompatibilities(GameMode::Osu);
+    ///
+    /// assert_eq!(
+    ///     incompatibilities,
+    ///     vec![
+    ///         ModIncompatibility::Exclusive(GameMods::Easy, GameMods::HardRock),
+    ///         ModIncompatibility::ModeRestricted(GameMods::Key4, GameMode::Osu),
+    ///     ]
+    /// );
+    ///
+    /// assert!(GameMods::Perfect.is_valid(GameMode::Mania));
+    /// assert!(!(GameMods::NightCore | GameMods::HalfTime).is_valid(GameMode::Taiko));
+    /// ```
+    pub fn incompatibilities(self, mode: GameMode) -> Vec<ModIncompatibility> {
+        let mut incompatibilities = Vec::new();
+
+        for &(a, b) in EXCLUSIVE_MODS.iter() {
+            if self.contains(a) && self.contains(b) {
+                let a = self.most_specific(a);
+                let b = self.most_specific(b);
+                incompatibilities.push(ModIncompatibility::Exclusive(a, b));
+            }
+        }
+
+        let keys = self & KEY_MODS;
+
+        if keys.bits.count_ones() > 1 {
+            incompatibilities.push(ModIncompatibility::MultipleKeyMods(keys));
+        }
+
+        let restricted = match mode {
+            GameMode::Osu => MANIA_ONLY,
+            GameMode::Taiko | GameMode::Catch => MANIA_ONLY | OSU_ONLY,
+            GameMode::Mania => OSU_ONLY | GameMods::Relax,
+        };
+
+        let restricted = self & restricted;
+
+        if !restricted.is_empty() {
+            incompatibilities.push(ModIncompatibility::ModeRestricted(restricted, mode));
+        }
+
+        incompatibilities
+    }
+
+    /// Check whether the mods are a valid combination for the given mode.
+    ///
+    /// See [`GameMods::incompatibilities`] for the reasons if they are not.
+    #[inline]
+    pub fn is_valid(self, mode: GameMode) -> bool {
+        self.incompatibilities(mode).is_empty()
+    }
+
+    /// Add mods that are implied by other mods i.e. DT for NC and SD for PF.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_v2::model::GameMods;
+    ///
+    /// let mods = GameMods::from_bits(512 + 16_384).unwrap();
+    /// assert_eq!(mods.normalize(), GameMods::NightCore | GameMods::Perfect);
+    /// ```
+    pub fn normalize(self) -> Self {
+        let mut mods = self;
+
+        if mods.bits & (GameMods::NightCore.bits & !GameMods::DoubleTime.bits) > 0 {
+            mods.insert(GameMods::NightCore);
+        }
+
+        if mods.bits & (GameMods::Perfect.bits & !GameMods::SuddenDeath.bits) > 0 {
+            mods.insert(GameMods::Perfect);
+        }
+
+        mods
+    }
+
+    /// Reduce the mods to those that affect difficulty attributes in the given mode.
+    ///
+    /// NC is considered to be DT and mods without effect on the difficulty
+    /// are removed so that the result can be used as key to cache
+    /// [`BeatmapDifficultyAttributes`](crate::model::beatmap::BeatmapDifficultyAttributes).
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_v2::model::{GameMode, GameMods};
+    ///
+    /// let mods = GameMods::Hidden | GameMods::NightCore | GameMods::Perfect;
+    /// assert_eq!(mods.difficulty_mods(GameMode::Osu), GameMods::Hidden | GameMods::DoubleTime);
+    /// assert_eq!(mods.difficulty_mods(GameMode::Taiko), GameMods::DoubleTime);
+    /// ```
+    pub fn difficulty_mods(self, mode: GameMode) -> Self {
+        let relevant = match mode {
+            GameMode::Osu => {
+                GameMods::Easy
+                    | GameMods::HardRock
+                    | GameMods::DoubleTime
+                    | GameMods::HalfTime
+                    | GameMods::Hidden
+                    | GameMods::Flashlight
+                    | GameMods::TouchDevice
+                    | GameMods::Relax
+                    | GameMods::Autopilot
+            }
+            GameMode::Taiko | GameMode::Catch => {
+                GameMods::Easy | GameMods::HardRock | GameMods::DoubleTime | GameMods::HalfTime
+            }
+            GameMode::Mania => {
+                GameMods::Easy
+                    | GameMods::HardRock
+                    | GameMods::DoubleTime
+                    | GameMods::HalfTime
+                    | KEY_MODS
+                    | GameMods::KeyCoop
+            }
+        };
+
+        self.normalize() & relevant
+    }
+
+    /// Parse a single mod from its full name as used by the game client e.g. `"DoubleTime"`
+    /// or from its acronym e.g. `"DT"`, ignoring case.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_v2::model::GameMods;
+    ///
+    /// assert_eq!(GameMods::from_name("Nightcore"), Some(GameMods::NightCore));
+    /// assert_eq!(GameMods::from_name("relax2"), Some(GameMods::Autopilot));
+    /// assert_eq!(GameMods::from_name("4K"), Some(GameMods::Key4));
+    /// assert_eq!(GameMods::from_name("HDHR"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<GameMods> {
+        let name = name.to_ascii_lowercase();
+
+        let m = match name.as_str() {
+            "nomod" | "none" => GameMods::NoMod,
+            "nofail" => GameMods::NoFail,
+            "easy" => GameMods::Easy,
+            "touchdevice" | "novideo" => GameMods::TouchDevice,
+            "hidden" => GameMods::Hidden,
+            "hardrock" => GameMods::HardRock,
+            "suddendeath" => GameMods::SuddenDeath,
+            "doubletime" => GameMods::DoubleTime,
+            "relax" => GameMods::Relax,
+            "halftime" => GameMods::HalfTime,
+            "nightcore" => GameMods::NightCore,
+            "flashlight" => GameMods::Flashlight,
+            "autoplay" | "auto" => GameMods::Autoplay,
+            "spunout" => GameMods::SpunOut,
+            "relax2" | "autopilot" => GameMods::Autopilot,
+            "perfect" => GameMods::Perfect,
+            "fadein" => GameMods::FadeIn,
+            "random" => GameMods::Random,
+            "cinema" => GameMods::Cinema,
+            "target" | "targetpractice" => GameMods::Target,
+            "scorev2" => GameMods::ScoreV2,
+            "mirror" => GameMods::Mirror,
+            "key1" => GameMods::Key1,
+            "key2" => GameMods::Key2,
+            "key3" => GameMods::Key3,
+            "key4" => GameMods::Key4,
+            "key5" => GameMods::Key5,
+            "key6" => GameMods::Key6,
+            "key7" => GameMods::Key7,
+            "key8" => GameMods::Key8,
+            "key9" => GameMods::Key9,
+            "keycoop" | "coop" => GameMods::KeyCoop,
+            _ => return GameMods::from_str(&name).ok().filter(|m| m.len() <= 1),
+        };
+
+        Some(m)
+    }
+
+    fn most_specific(self, m: GameMods) -> GameMods {
+        if m == GameMods::DoubleTime && self.contains(GameMods::NightCore) {
+            GameMods::NightCore
+        } else if m == GameMods::SuddenDeath && self.contains(GameMods::Perfect) {
+            GameMods::Perfect
+        } else {
+            m
+        }
+    }
+}
+
+const KEY_MODS: GameMods = GameMods::from_bits_truncate(
+    GameMods::Key1.bits
+        | GameMods::Key2.bits
+        | GameMods::Key3.bits
+        | GameMods::Key4.bits
+        | GameMods::Key5.bits
+        | GameMods::Key6.bits
+        | GameMods::Key7.bits
+        | GameMods::Key8.bits
+        | GameMods::Key9.bits,
+);
+
+const MANIA_ONLY: GameMods = GameMods::from_bits_truncate(
+    KEY_MODS.bits
+        | GameMods::KeyCoop.bits
+        | GameMods::FadeIn.bits
+        | GameMods::Random.bits
+        | GameMods::Mirror.bits,
+);
+
+const OSU_ONLY: GameMods = GameMods::from_bits_truncate(
+    GameMods::Autopilot.bits
+        | GameMods::SpunOut.bits
+        | GameMods::Target.bits
+        | GameMods::TouchDevice.bits,
+);
+
+const EXCLUSIVE_MODS: [(GameMods, GameMods); 9] = [
+    (GameMods::Easy, GameMods::HardRock),
+    (GameMods::DoubleTime, GameMods::HalfTime),
+    (GameMods::NoFail, GameMods::SuddenDeath),
+    (GameMods::Hidden, GameMods::FadeIn),
+    (GameMods::Relax, GameMods::Autopilot),
+    (GameMods::NoFail, GameMods::Relax),
+    (GameMods::SuddenDeath, GameMods::Relax),
+    (GameMods::NoFail, GameMods::Autopilot),
+    (GameMods::SuddenDeath, GameMods::Autopilot),
+];
+
+/// Reason why [`GameMods`] are not a valid combination, see [`GameMods::incompatibilities`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModIncompatibility {
+    /// The two mods can not be combined e.g. EZ and HR
+    Exclusive(GameMods, GameMods),
+    /// More than one of osu!mania's key mods are specified
+    MultipleKeyMods(GameMods),
+    /// The mods are not available in the mode
+    ModeRestricted(GameMods, GameMode),
+}
+
+impl fmt::Display for ModIncompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exclusive(a, b) => write!(f, "{} and {} can not be combined", a, b),
+            Self::MultipleKeyMods(mods) => write!(f, "multiple key mods: {}", mods),
+            Self::ModeRestricted(mods, mode) => write!(f, "{} not available in {}", mods, mode),
+        }
+    }
 }
 
 impl fmt::Display for GameMods {
@@ -614,6 +864,31 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn mods_incompatibilities() {
+        let mods = GameMods::NightCore | GameMods::HalfTime | GameMods::Perfect | GameMods::NoFail;
+
+        assert_eq!(
+            mods.incompatibilities(GameMode::Taiko),
+            vec![
+                ModIncompatibility::Exclusive(GameMods::NightCore, GameMods::HalfTime),
+                ModIncompatibility::Exclusive(GameMods::NoFail, GameMods::Perfect),
+            ]
+        );
+
+        let mods = GameMods::Key4 | GameMods::Key7 | GameMods::Relax;
+
+        assert_eq!(
+            mods.incompatibilities(GameMode::Mania),
+            vec![
+                ModIncompatibility::MultipleKeyMods(GameMods::Key4 | GameMods::Key7),
+                ModIncompatibility::ModeRestricted(GameMods::Relax, GameMode::Mania),
+            ]
+        );
+
+        assert!((GameMods::Hidden | GameMods::HardRock).is_valid(GameMode::Catch));
+    }
+
     #[test]
     fn cut() {
         let mut iter = util::cut("hDHrdTv2n", 2);
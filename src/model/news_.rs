@@ -1,5 +1,5 @@
 use super::{serde_, Cursor};
-use crate::{prelude::Username, Osu, OsuResult};
+use crate::{prelude::Username, request::GetNewsPost, Osu, OsuResult};
 
 use serde::Deserialize;
 
@@ -13,7 +13,7 @@ use time::OffsetDateTime;
 // #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct News {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub(crate) cursor: Option<Cursor>,
+    pub cursor: Option<Cursor>,
     #[serde(rename = "news_posts")]
     pub posts: Vec<NewsPost>,
     pub search: NewsSearch,
@@ -45,10 +45,17 @@ pub struct NewsPost {
     pub post_id: u32,
     #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameWrapper))]
     pub author: Username,
+    /// HTML content of the post; only available when requesting a single post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
     /// Link to the file view on GitHub.
     pub edit_url: String,
     /// Link to the first image in the document.
     pub first_image: String,
+    /// The posts published before and after this one;
+    /// only available when requesting a single post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub navigation: Option<NewsNavigation>,
     #[serde(with = "serde_::datetime")]
     #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
     pub published_at: OffsetDateTime,
@@ -67,6 +74,28 @@ pub struct NewsPost {
     pub preview: Option<String>,
 }
 
+impl NewsPost {
+    /// Request the next newer [`NewsPost`] if there is one.
+    ///
+    /// Only works if `navigation` is `Some`.
+    #[inline]
+    pub fn get_newer<'o>(&self, osu: &'o Osu) -> Option<GetNewsPost<'o>> {
+        let newer = self.navigation.as_ref()?.newer.as_deref()?;
+
+        Some(osu.news_post(newer.slug.as_str()))
+    }
+
+    /// Request the next older [`NewsPost`] if there is one.
+    ///
+    /// Only works if `navigation` is `Some`.
+    #[inline]
+    pub fn get_older<'o>(&self, osu: &'o Osu) -> Option<GetNewsPost<'o>> {
+        let older = self.navigation.as_ref()?.older.as_deref()?;
+
+        Some(osu.news_post(older.slug.as_str()))
+    }
+}
+
 impl PartialEq for NewsPost {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -76,6 +105,28 @@ impl PartialEq for NewsPost {
 
 impl Eq for NewsPost {}
 
+/// The neighbouring posts of a [`NewsPost`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvDeserialize, RkyvSerialize),
+    archive(bound(
+        serialize = "__S: rkyv::ser::Serializer + Sized",
+        deserialize = "__D: Sized"
+    ))
+)]
+pub struct NewsNavigation {
+    /// The next newer post
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", omit_bounds)]
+    pub newer: Option<Box<NewsPost>>,
+    /// The next older post
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", omit_bounds)]
+    pub older: Option<Box<NewsPost>>,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 // TODO
@@ -0,0 +1,522 @@
+use super::{comments_::CommentableType, serde_, user_::Username, GameMode};
+use crate::{
+    request::{GetBeatmapset, GetComments, GetForumPosts},
+    Osu, OsuResult,
+};
+
+use serde::{
+    de::{Deserializer, Error, IgnoredAny, MapAccess, Visitor},
+    Deserialize,
+};
+use serde_json::Value;
+use std::fmt;
+use time::OffsetDateTime;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// A page of the authenticated user's notifications.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Notifications {
+    /// Whether there are older notifications
+    pub has_more: bool,
+    /// Notifications of the current page, newest first
+    pub notifications: Vec<Notification>,
+    /// Total amount of unread notifications
+    pub unread_count: u32,
+    /// Url of the websocket that pushes new notifications
+    pub notification_endpoint: String,
+}
+
+impl Notifications {
+    /// Returns whether there are older notifications,
+    /// retrievable via [`get_next`](Notifications::get_next).
+    #[inline]
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// If [`has_more`](Notifications::has_more) is true, the API can provide the next set of notifications and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<Notifications>> {
+        if !self.has_more {
+            return None;
+        }
+
+        let oldest = self
+            .notifications
+            .iter()
+            .map(|notification| notification.notification_id)
+            .min()?;
+
+        Some(osu.notifications().max_id(oldest.saturating_sub(1)).await)
+    }
+}
+
+/// A single notification of the authenticated user.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Notification {
+    #[serde(rename = "id")]
+    pub notification_id: u64,
+    #[serde(with = "serde_::datetime")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
+    pub created_at: OffsetDateTime,
+    pub is_read: bool,
+    /// ID of the object the notification is about
+    pub object_id: u64,
+    /// Type of the object the notification is about
+    /// e.g. `"beatmapset"`, `"forum_topic"`, or `"channel"`
+    pub object_type: String,
+    /// ID of the user that triggered the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_user_id: Option<u32>,
+    #[serde(flatten)]
+    pub kind: NotificationKind,
+}
+
+impl Notification {
+    /// Returns the category of the notification;
+    /// `None` for [`NotificationKind::Other`].
+    #[inline]
+    pub fn category(&self) -> Option<NotificationCategory> {
+        self.kind.category()
+    }
+
+    /// Request the [`Beatmapset`](crate::model::beatmap::Beatmapset)
+    /// that the notification is about.
+    ///
+    /// Only works if the notification's object is a beatmapset.
+    #[inline]
+    pub fn get_beatmapset<'o>(&self, osu: &'o Osu) -> Option<GetBeatmapset<'o>> {
+        (self.object_type == "beatmapset").then(|| osu.beatmapset(self.object_id as u32))
+    }
+
+    /// Request the [`CommentBundle`](crate::model::comments::CommentBundle)
+    /// of the object that a [`Comment`](crate::model::comments::Comment) was posted on.
+    ///
+    /// Only works for comment notifications.
+    #[inline]
+    pub fn get_comments<'o>(&self, osu: &'o Osu) -> Option<GetComments<'o>> {
+        if !matches!(
+            self.kind,
+            NotificationKind::CommentNew(_) | NotificationKind::CommentReply(_)
+        ) {
+            return None;
+        }
+
+        let commentable_type = match self.object_type.as_str() {
+            "beatmapset" => CommentableType::Beatmapset,
+            "build" => CommentableType::Build,
+            "news_post" => CommentableType::NewsPost,
+            _ => return None,
+        };
+
+        let fut = osu
+            .comments()
+            .commentable_type(commentable_type)
+            .commentable_id(self.object_id as u32);
+
+        Some(fut)
+    }
+
+    /// Request the [`ForumPosts`](crate::model::forum::ForumPosts) including
+    /// the [`ForumTopic`](crate::model::forum::ForumTopic) that the notification is about.
+    ///
+    /// Only works if the notification's object is a forum topic.
+    #[inline]
+    pub fn get_forum_posts<'o>(&self, osu: &'o Osu) -> Option<GetForumPosts<'o>> {
+        (self.object_type == "forum_topic").then(|| osu.forum_posts(self.object_id))
+    }
+
+    /// The category name as expected by the API; `None` for [`NotificationKind::Other`]
+    /// since its category is unknown.
+    pub(crate) fn category_name(&self) -> Option<&'static str> {
+        self.category().map(NotificationCategory::as_str)
+    }
+}
+
+/// The type of a [`Notification`] including its details
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub enum NotificationKind {
+    /// The ownership of a beatmap was transferred to the user
+    BeatmapOwnerChange(BeatmapOwnerChangeDetails),
+    /// A beatmapset discussion of one of the user's beatmapsets or
+    /// of a watched beatmapset was updated
+    BeatmapsetDiscussion {
+        event: BeatmapsetDiscussionEvent,
+        details: BeatmapsetDiscussionDetails,
+    },
+    /// The user received a chat message
+    ChannelMessage(ChannelMessageDetails),
+    /// A new comment was posted on a watched object
+    CommentNew(CommentNotificationDetails),
+    /// Someone replied to one of the user's comments
+    CommentReply(CommentNotificationDetails),
+    /// Someone replied in a watched forum topic
+    ForumTopicReply(ForumTopicReplyDetails),
+    /// The user unlocked a medal
+    UserAchievementUnlock(UserAchievementDetails),
+    /// Any other notification, identified by its name
+    Other { name: String },
+}
+
+impl NotificationKind {
+    /// Returns the category of the notification;
+    /// `None` for [`NotificationKind::Other`].
+    pub fn category(&self) -> Option<NotificationCategory> {
+        let category = match self {
+            Self::BeatmapOwnerChange(_) => NotificationCategory::BeatmapOwnerChange,
+            Self::BeatmapsetDiscussion { .. } => NotificationCategory::BeatmapsetDiscussion,
+            Self::ChannelMessage(_) => NotificationCategory::Channel,
+            Self::CommentNew(_) | Self::CommentReply(_) => NotificationCategory::Comment,
+            Self::ForumTopicReply(_) => NotificationCategory::ForumTopicReply,
+            Self::UserAchievementUnlock(_) => NotificationCategory::UserAchievementUnlock,
+            Self::Other { .. } => return None,
+        };
+
+        Some(category)
+    }
+
+    /// Returns the name of the notification as provided by the API
+    pub fn name(&self) -> &str {
+        match self {
+            Self::BeatmapOwnerChange(_) => "beatmap_owner_change",
+            Self::BeatmapsetDiscussion { event, .. } => event.name(),
+            Self::ChannelMessage(_) => "channel_message",
+            Self::CommentNew(_) => "comment_new",
+            Self::CommentReply(_) => "comment_reply",
+            Self::ForumTopicReply(_) => "forum_topic_reply",
+            Self::UserAchievementUnlock(_) => "user_achievement_unlock",
+            Self::Other { name } => name,
+        }
+    }
+}
+
+struct NotificationKindVisitor;
+
+impl<'de> Visitor<'de> for NotificationKindVisitor {
+    type Value = NotificationKind;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("notification name and details")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut name: Option<String> = None;
+        let mut details: Option<Value> = None;
+
+        // Keys are not necessarily borrowable since the flattened content is buffered
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            Name,
+            Details,
+            #[serde(other)]
+            Other,
+        }
+
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Name => name = Some(map.next_value()?),
+                Field::Details => details = Some(map.next_value()?),
+                Field::Other => {
+                    let _: IgnoredAny = map.next_value()?;
+                }
+            }
+        }
+
+        let name = name.ok_or_else(|| Error::missing_field("name"))?;
+        let details = details.unwrap_or(Value::Null);
+
+        macro_rules! details {
+            () => {
+                serde_json::from_value(details).map_err(Error::custom)?
+            };
+        }
+
+        let event = match name.as_str() {
+            "beatmapset_discussion_lock" => Some(BeatmapsetDiscussionEvent::Lock),
+            "beatmapset_discussion_post_new" => Some(BeatmapsetDiscussionEvent::PostNew),
+            "beatmapset_discussion_qualified_problem" => {
+                Some(BeatmapsetDiscussionEvent::QualifiedProblem)
+            }
+            "beatmapset_discussion_review_new" => Some(BeatmapsetDiscussionEvent::ReviewNew),
+            "beatmapset_discussion_unlock" => Some(BeatmapsetDiscussionEvent::Unlock),
+            _ => None,
+        };
+
+        if let Some(event) = event {
+            return Ok(NotificationKind::BeatmapsetDiscussion {
+                event,
+                details: details!(),
+            });
+        }
+
+        let kind = match name.as_str() {
+            "beatmap_owner_change" => NotificationKind::BeatmapOwnerChange(details!()),
+            "channel_message" => NotificationKind::ChannelMessage(details!()),
+            "comment_new" => NotificationKind::CommentNew(details!()),
+            "comment_reply" => NotificationKind::CommentReply(details!()),
+            "forum_topic_reply" => NotificationKind::ForumTopicReply(details!()),
+            "user_achievement_unlock" => NotificationKind::UserAchievementUnlock(details!()),
+            _ => NotificationKind::Other { name },
+        };
+
+        Ok(kind)
+    }
+}
+
+impl<'de> Deserialize<'de> for NotificationKind {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_map(NotificationKindVisitor)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for NotificationKind {
+    fn serialize<S: serde::ser::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = s.serialize_map(Some(2))?;
+        map.serialize_entry("name", self.name())?;
+
+        match self {
+            Self::BeatmapOwnerChange(details) => map.serialize_entry("details", details)?,
+            Self::BeatmapsetDiscussion { details, .. } => {
+                map.serialize_entry("details", details)?
+            }
+            Self::ChannelMessage(details) => map.serialize_entry("details", details)?,
+            Self::CommentNew(details) | Self::CommentReply(details) => {
+                map.serialize_entry("details", details)?
+            }
+            Self::ForumTopicReply(details) => map.serialize_entry("details", details)?,
+            Self::UserAchievementUnlock(details) => map.serialize_entry("details", details)?,
+            Self::Other { .. } => {}
+        }
+
+        map.end()
+    }
+}
+
+/// Categories by which notifications are grouped,
+/// e.g. to mark them as read all at once
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvDeserialize, RkyvSerialize),
+    archive(as = "Self")
+)]
+pub enum NotificationCategory {
+    BeatmapOwnerChange,
+    BeatmapsetDiscussion,
+    Channel,
+    Comment,
+    ForumTopicReply,
+    UserAchievementUnlock,
+}
+
+impl NotificationCategory {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::BeatmapOwnerChange => "beatmap_owner_change",
+            Self::BeatmapsetDiscussion => "beatmapset_discussion",
+            Self::Channel => "channel",
+            Self::Comment => "comment",
+            Self::ForumTopicReply => "forum_topic_reply",
+            Self::UserAchievementUnlock => "user_achievement_unlock",
+        }
+    }
+}
+
+impl fmt::Display for NotificationCategory {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What happened in a beatmapset discussion
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvDeserialize, RkyvSerialize),
+    archive(as = "Self")
+)]
+pub enum BeatmapsetDiscussionEvent {
+    /// The discussion was locked
+    Lock,
+    /// A new post was made
+    PostNew,
+    /// A problem was reported on a qualified beatmapset
+    QualifiedProblem,
+    /// A new review was posted
+    ReviewNew,
+    /// The discussion was unlocked
+    Unlock,
+}
+
+impl BeatmapsetDiscussionEvent {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Lock => "beatmapset_discussion_lock",
+            Self::PostNew => "beatmapset_discussion_post_new",
+            Self::QualifiedProblem => "beatmapset_discussion_qualified_problem",
+            Self::ReviewNew => "beatmapset_discussion_review_new",
+            Self::Unlock => "beatmapset_discussion_unlock",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct BeatmapOwnerChangeDetails {
+    pub beatmap_id: u32,
+    /// Difficulty name of the beatmap
+    pub version: String,
+    /// Title of the beatmapset
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_unicode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct BeatmapsetDiscussionDetails {
+    /// Title of the beatmapset
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_unicode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beatmap_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discussion_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<u64>,
+    /// Content of the post
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Username of the user that triggered the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameMap))]
+    pub username: Option<Username>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ChannelMessageDetails {
+    /// Preview of the message
+    pub title: String,
+    /// Type of the channel e.g. `"pm"`
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub channel_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    /// Username of the sender
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameMap))]
+    pub username: Option<Username>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct CommentNotificationDetails {
+    /// ID of the new [`Comment`](crate::model::comments::Comment)
+    pub comment_id: u32,
+    /// Title of the object the comment was posted on
+    pub title: String,
+    /// Content of the comment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    /// Username of the comment's author
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameMap))]
+    pub username: Option<Username>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct ForumTopicReplyDetails {
+    /// Title of the forum topic
+    pub title: String,
+    /// ID of the new [`ForumPost`](crate::model::forum::ForumPost)
+    pub post_id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    /// Username of the post's author
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameMap))]
+    pub username: Option<Username>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct UserAchievementDetails {
+    #[serde(rename = "achievement_id")]
+    pub medal_id: u32,
+    #[serde(
+        default,
+        rename = "achievement_mode",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mode: Option<GameMode>,
+    /// Name of the medal
+    pub title: String,
+    pub slug: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_from_value() {
+        let json = serde_json::json!({
+            "id": 1,
+            "name": "comment_new",
+            "created_at": "2021-01-01T00:00:00+00:00",
+            "object_type": "news_post",
+            "object_id": 2,
+            "source_user_id": 3,
+            "is_read": false,
+            "details": {
+                "comment_id": 4,
+                "title": "title",
+                "content": "content",
+                "username": "peppy"
+            }
+        });
+
+        let notification: Notification = serde_json::from_value(json.clone()).unwrap();
+        let from_reader: Notification =
+            serde_json::from_reader(json.to_string().as_bytes()).unwrap();
+
+        assert_eq!(notification, from_reader);
+        assert_eq!(notification.notification_id, 1);
+        assert_eq!(notification.kind.name(), "comment_new");
+        assert_eq!(notification.category(), Some(NotificationCategory::Comment));
+    }
+}
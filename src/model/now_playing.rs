@@ -0,0 +1,262 @@
+use super::{GameMode, GameMods, OsuLink};
+use crate::{
+    error::{OsuError, ParsingError},
+    request::{GetBeatmap, GetBeatmapDifficultyAttributes, GetBeatmapset},
+    Osu,
+};
+
+use std::{fmt, str::FromStr};
+
+/// A "now playing" message as sent by the osu! client through the `/np` command.
+///
+/// Such messages are `ACTION`s of the form
+/// `is listening to [https://osu.ppy.sh/beatmapsets/1#/2 Artist - Title [Diff]] +Hidden +DoubleTime |osu!|`
+/// where the `ACTION` prefix as well as the surrounding `\x01` of IRC are optional.
+///
+/// Mods are parsed from the full names that the game client uses and the mode defaults
+/// to the mode of the link, or osu!standard if neither specifies one.
+///
+/// ```
+/// use rosu_v2::prelude::*;
+///
+/// let msg = "\x01ACTION is playing [https://osu.ppy.sh/b/75 Kenji Ninuma - DISCO PRINCE [Normal]] \
+///     +Hidden +DoubleTime <Taiko>\x01";
+/// let np: NowPlaying = msg.parse().unwrap();
+///
+/// assert_eq!(np.status, NowPlayingStatus::Playing);
+/// assert_eq!(np.map_id, Some(75));
+/// assert_eq!(np.title, "Kenji Ninuma - DISCO PRINCE [Normal]");
+/// assert_eq!(np.mods, GameMods::Hidden | GameMods::DoubleTime);
+/// assert_eq!(np.mode, GameMode::Taiko);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NowPlaying {
+    pub status: NowPlayingStatus,
+    /// `None` if the message links to a beatmapset without specifying a difficulty
+    pub map_id: Option<u32>,
+    /// `None` if the message links to a beatmap instead of a beatmapset
+    pub mapset_id: Option<u32>,
+    /// The displayed title, usually `Artist - Title [Difficulty]`
+    pub title: String,
+    pub mods: GameMods,
+    pub mode: GameMode,
+}
+
+/// What a user was doing when sending a [`NowPlaying`] message
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NowPlayingStatus {
+    Listening,
+    Playing,
+    Watching,
+    Editing,
+}
+
+impl NowPlaying {
+    /// Only works if `map_id` is `Some`.
+    #[inline]
+    pub fn get_beatmap<'o>(&self, osu: &'o Osu) -> Option<GetBeatmap<'o>> {
+        self.map_id.map(|map_id| osu.beatmap().map_id(map_id))
+    }
+
+    /// Only works if `mapset_id` is `Some`.
+    #[inline]
+    pub fn get_beatmapset<'o>(&self, osu: &'o Osu) -> Option<GetBeatmapset<'o>> {
+        self.mapset_id.map(|mapset_id| osu.beatmapset(mapset_id))
+    }
+
+    /// Request the difficulty attributes of the map with the message's mode and mods.
+    ///
+    /// Only works if `map_id` is `Some`.
+    #[inline]
+    pub fn get_difficulty_attributes<'o>(
+        &self,
+        osu: &'o Osu,
+    ) -> Option<GetBeatmapDifficultyAttributes<'o>> {
+        self.map_id.map(|map_id| {
+            osu.beatmap_difficulty_attributes(map_id)
+                .mode(self.mode)
+                .mods(self.mods)
+        })
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim().trim_matches('\u{1}').trim();
+        let s = s.strip_prefix("ACTION ").unwrap_or(s).trim_start();
+
+        let s = s.strip_prefix("is ")?;
+
+        let (status, rest) = if let Some(rest) = s.strip_prefix("listening to ") {
+            (NowPlayingStatus::Listening, rest)
+        } else if let Some(rest) = s.strip_prefix("playing ") {
+            (NowPlayingStatus::Playing, rest)
+        } else if let Some(rest) = s.strip_prefix("watching ") {
+            (NowPlayingStatus::Watching, rest)
+        } else if let Some(rest) = s.strip_prefix("editing ") {
+            (NowPlayingStatus::Editing, rest)
+        } else {
+            return None;
+        };
+
+        let rest = rest.trim_start().strip_prefix('[')?;
+
+        // Titles may contain unbalanced brackets but the tail of mods and mode
+        // never contains any so the last one closes the link
+        let end = rest.rfind(']')?;
+
+        let (inner, tail) = (&rest[..end], &rest[end + 1..]);
+
+        let (url, title) = inner.split_once(' ').unwrap_or((inner, ""));
+
+        let (map_id, mapset_id, link_mode) = match url.parse::<OsuLink>() {
+            Ok(OsuLink::Map { map_id, mode }) => (Some(map_id), None, mode),
+            Ok(OsuLink::Mapset {
+                mapset_id,
+                map_id,
+                mode,
+            }) => (map_id, Some(mapset_id), mode),
+            _ => return None,
+        };
+
+        let mut mods = GameMods::NoMod;
+        let mut mode = None;
+
+        for token in tail.split_whitespace() {
+            let trimmed = token.trim_matches(|c| matches!(c, '+' | '-' | '~' | '|' | '<' | '>'));
+
+            if trimmed.is_empty() {
+                continue;
+            } else if let Some(token_mode) = parse_mode_name(trimmed) {
+                mode = Some(token_mode);
+            } else if token.starts_with(['+', '-', '~', '|']) {
+                // Unknown mods would falsify any calculation so they're not skipped
+                mods |= GameMods::from_name(trimmed)?;
+            }
+        }
+
+        Some(Self {
+            status,
+            map_id,
+            mapset_id,
+            title: title.trim().to_owned(),
+            mods,
+            mode: mode.or(link_mode).unwrap_or_default(),
+        })
+    }
+}
+
+impl FromStr for NowPlaying {
+    type Err = OsuError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| ParsingError::NowPlaying(s.to_owned()).into())
+    }
+}
+
+impl fmt::Display for NowPlayingStatus {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Listening => f.write_str("listening"),
+            Self::Playing => f.write_str("playing"),
+            Self::Watching => f.write_str("watching"),
+            Self::Editing => f.write_str("editing"),
+        }
+    }
+}
+
+fn parse_mode_name(s: &str) -> Option<GameMode> {
+    match s.to_ascii_lowercase().as_str() {
+        "osu!" | "osu" | "osu!standard" | "standard" => Some(GameMode::Osu),
+        "taiko" | "osu!taiko" => Some(GameMode::Taiko),
+        "catchthebeat" | "catch" | "osu!catch" | "fruits" => Some(GameMode::Catch),
+        "mania" | "osu!mania" => Some(GameMode::Mania),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listening_to_mapset() {
+        let msg =
+            "ACTION is listening to [https://osu.ppy.sh/beatmapsets/1#/2 Artist - Title [Diff]] \
+            +Hidden +DoubleTime |osu!|";
+        let np: NowPlaying = msg.parse().unwrap();
+
+        let expected = NowPlaying {
+            status: NowPlayingStatus::Listening,
+            map_id: Some(2),
+            mapset_id: Some(1),
+            title: "Artist - Title [Diff]".to_owned(),
+            mods: GameMods::Hidden | GameMods::DoubleTime,
+            mode: GameMode::Osu,
+        };
+
+        assert_eq!(np, expected);
+    }
+
+    #[test]
+    fn mod_prefixes_and_modes() {
+        let msg = "\u{1}ACTION is playing [https://osu.ppy.sh/b/123 A - B [[Nested] Diff]] \
+            -Easy -NoFail ~Relax~ +Nightcore <osu!mania> +4K\u{1}";
+        let np: NowPlaying = msg.parse().unwrap();
+
+        assert_eq!(np.title, "A - B [[Nested] Diff]");
+        assert_eq!(np.map_id, Some(123));
+        assert_eq!(np.mapset_id, None);
+        assert_eq!(
+            np.mods,
+            GameMods::Easy
+                | GameMods::NoFail
+                | GameMods::Relax
+                | GameMods::NightCore
+                | GameMods::Key4
+        );
+        assert_eq!(np.mode, GameMode::Mania);
+
+        let np: NowPlaying =
+            "is editing [https://osu.ppy.sh/beatmapsets/1#taiko/2 Artist - Title [Oni]]"
+                .parse()
+                .unwrap();
+        assert_eq!(np.status, NowPlayingStatus::Editing);
+        assert_eq!(np.mods, GameMods::NoMod);
+        assert_eq!(np.mode, GameMode::Taiko);
+    }
+
+    #[test]
+    fn unbalanced_titles() {
+        let np: NowPlaying = "is listening to [https://osu.ppy.sh/b/1 A - B :] [C]] +Hidden"
+            .parse()
+            .unwrap();
+        assert_eq!(np.title, "A - B :] [C]");
+        assert_eq!(np.mods, GameMods::Hidden);
+
+        let np: NowPlaying = "is playing [https://osu.ppy.sh/b/1 A - [B [C]] <Taiko>"
+            .parse()
+            .unwrap();
+        assert_eq!(np.title, "A - [B [C]");
+        assert_eq!(np.mode, GameMode::Taiko);
+
+        let np: NowPlaying = "is playing [https://osu.ppy.sh/b/1 A - B [C]"
+            .parse()
+            .unwrap();
+        assert_eq!(np.title, "A - B [C");
+    }
+
+    #[test]
+    fn invalid_messages() {
+        assert!("is listening to something".parse::<NowPlaying>().is_err());
+        assert!("is playing [https://osu.ppy.sh/b/1 A - B"
+            .parse::<NowPlaying>()
+            .is_err());
+        assert!("is playing [https://osu.ppy.sh/users/2 peppy]"
+            .parse::<NowPlaying>()
+            .is_err());
+        assert!("is playing [https://osu.ppy.sh/b/1 A - B [C]] +Unknown"
+            .parse::<NowPlaying>()
+            .is_err());
+    }
+}
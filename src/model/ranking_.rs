@@ -635,3 +635,30 @@ impl PartialEq for Spotlight {
 }
 
 impl Eq for Spotlight {}
+
+/// The rank that a pp value would have on the performance leaderboard,
+/// see [`Osu::estimate_rank`](crate::Osu::estimate_rank).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RankEstimate {
+    /// The rank is within the leaderboard
+    Exact(u32),
+    /// The pp value is lower than the pp of all users on the last
+    /// available leaderboard page so the rank is at least the given one
+    AtLeast(u32),
+}
+
+impl RankEstimate {
+    /// The estimated rank, or its lower bound if it is not exact.
+    #[inline]
+    pub fn rank(self) -> u32 {
+        match self {
+            Self::Exact(rank) | Self::AtLeast(rank) => rank,
+        }
+    }
+
+    /// Whether the rank is within the leaderboard.
+    #[inline]
+    pub fn is_exact(self) -> bool {
+        matches!(self, Self::Exact(_))
+    }
+}
@@ -7,10 +7,53 @@ use super::{
     user_::{Medal, Username},
     GameMode, Grade,
 };
+use crate::{Osu, OsuResult};
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 
+/// A page of the global events feed.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Events {
+    #[serde(
+        default,
+        rename = "cursor_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) cursor: Option<String>,
+    /// Events of the current page
+    pub events: Vec<RecentEvent>,
+    #[serde(skip)]
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Skip))]
+    pub(crate) ascending: bool,
+}
+
+impl Events {
+    /// Returns whether there is a next page of events,
+    /// retrievable via [`get_next`](Events::get_next).
+    #[inline]
+    pub fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// If [`has_more`](Events::has_more) is true, the API can provide the next set of events and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<Events>> {
+        let fut = osu.events().cursor(self.cursor.clone()?);
+
+        let fut = if self.ascending {
+            fut.sort_ascending()
+        } else {
+            fut.sort_descending()
+        };
+
+        Some(fut.await)
+    }
+}
+
 /// The object has different attributes depending on its type.
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
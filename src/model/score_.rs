@@ -2,11 +2,11 @@ use super::{
     beatmap::{Beatmap, BeatmapsetCompact},
     serde_,
     user_::UserCompact,
-    GameMode, GameMods, Grade,
+    GameMode, GameMods, Grade, LazerMods,
 };
 use crate::{request::GetUser, Osu};
 
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
@@ -59,9 +59,24 @@ pub struct Score {
         skip_serializing_if = "Option::is_none"
     )]
     pub mapset: Option<BeatmapsetCompact>,
+    /// ID of the osu!lazer build the score was set on;
+    /// `None` for scores that were not set on osu!lazer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_id: Option<u32>,
+    /// The score's id on osu!lazer; only available for the osu!lazer format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The score's id on osu!stable; only available for the osu!lazer format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legacy_score_id: Option<u64>,
+    /// The score's total score on osu!stable; only available for the osu!lazer format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legacy_total_score: Option<u64>,
+    /// Deserialized from `ruleset_id` for osu!lazer scores
     #[serde(alias = "ruleset_id")]
     pub mode: GameMode,
-    pub mods: GameMods,
+    /// Includes osu!lazer mods and their settings, see [`LazerMods::legacy`] for the legacy mods
+    pub mods: LazerMods,
     #[serde(alias = "legacy_perfect")]
     pub perfect: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -70,12 +85,18 @@ pub struct Score {
     pub rank_country: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rank_global: Option<u32>,
+    #[serde(alias = "has_replay")]
     pub replay: Option<bool>,
     #[serde(alias = "total_score")]
     pub score: u32,
+    /// The id of the user's best osu!stable score on the map, i.e. a legacy score id
+    /// for the score's mode
     #[serde(rename = "best_id")]
     pub score_id: Option<u64>,
     pub statistics: ScoreStatistics,
+    /// Statistics of a perfect play on the map; only available for the osu!lazer format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_statistics: Option<ScoreStatistics>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<UserCompact>,
     pub user_id: u32,
@@ -140,7 +161,7 @@ pub(crate) struct Scores {
     pub(crate) scores: Vec<Score>,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(
     feature = "rkyv",
@@ -148,18 +169,84 @@ pub(crate) struct Scores {
     archive(as = "Self")
 )]
 pub struct ScoreStatistics {
-    #[serde(alias = "perfect", default)]
     pub count_geki: u32,
-    #[serde(alias = "great", default)]
     pub count_300: u32,
-    #[serde(alias = "good", alias = "small_tick_miss", default)]
     pub count_katu: u32,
-    #[serde(alias = "ok", alias = "large_tick_hit", default)]
     pub count_100: u32,
-    #[serde(alias = "meh", alias = "small_tick_hit", default)]
     pub count_50: u32,
-    #[serde(alias = "miss", default)]
     pub count_miss: u32,
+    /// Missed large ticks; osu!lazer only
+    pub large_tick_miss: u32,
+    /// Hit slider tails; osu!lazer only
+    pub slider_tail_hit: u32,
+    /// Hit small bonus objects such as spinner ticks; osu!lazer only
+    pub small_bonus: u32,
+    /// Hit large bonus objects such as spinner bonus ticks; osu!lazer only
+    pub large_bonus: u32,
+    /// Hit objects that don't affect accuracy; osu!lazer only
+    pub ignore_hit: u32,
+    /// Missed objects that don't affect accuracy; osu!lazer only
+    pub ignore_miss: u32,
+}
+
+/// osu!lazer names hit results by their judgement so the same key may
+/// describe different legacy counts, e.g. `large_tick_hit` are droplets in
+/// osu!catch but slider ticks in osu!standard which also has `ok`.
+/// The judgement names take precedence over the tick names.
+#[derive(Deserialize)]
+struct RawScoreStatistics {
+    #[serde(alias = "perfect", default)]
+    count_geki: u32,
+    #[serde(alias = "great", default)]
+    count_300: u32,
+    #[serde(alias = "good", default)]
+    count_katu: Option<u32>,
+    #[serde(default)]
+    small_tick_miss: Option<u32>,
+    #[serde(alias = "ok", default)]
+    count_100: Option<u32>,
+    #[serde(default)]
+    large_tick_hit: Option<u32>,
+    #[serde(alias = "meh", default)]
+    count_50: Option<u32>,
+    #[serde(default)]
+    small_tick_hit: Option<u32>,
+    #[serde(alias = "miss", default)]
+    count_miss: u32,
+    #[serde(default)]
+    large_tick_miss: u32,
+    #[serde(default)]
+    slider_tail_hit: u32,
+    #[serde(default)]
+    small_bonus: u32,
+    #[serde(default)]
+    large_bonus: u32,
+    #[serde(default)]
+    ignore_hit: u32,
+    #[serde(default)]
+    ignore_miss: u32,
+}
+
+impl<'de> Deserialize<'de> for ScoreStatistics {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let raw = <RawScoreStatistics as Deserialize>::deserialize(d)?;
+
+        Ok(Self {
+            count_geki: raw.count_geki,
+            count_300: raw.count_300,
+            count_katu: raw.count_katu.or(raw.small_tick_miss).unwrap_or(0),
+            count_100: raw.count_100.or(raw.large_tick_hit).unwrap_or(0),
+            count_50: raw.count_50.or(raw.small_tick_hit).unwrap_or(0),
+            count_miss: raw.count_miss,
+            large_tick_miss: raw.large_tick_miss,
+            slider_tail_hit: raw.slider_tail_hit,
+            small_bonus: raw.small_bonus,
+            large_bonus: raw.large_bonus,
+            ignore_hit: raw.ignore_hit,
+            ignore_miss: raw.ignore_miss,
+        })
+    }
 }
 
 impl ScoreStatistics {
@@ -207,6 +294,154 @@ impl ScoreStatistics {
 
         (10_000.0 * numerator / denumerator).round() / 100.0
     }
+
+    /// Generate hit results for the given amount of hit objects
+    /// with the given accuracy i.e. `0 <= accuracy <= 100` and amount of misses.
+    ///
+    /// The hit results are chosen such that their [`accuracy`](ScoreStatistics::accuracy)
+    /// matches the given accuracy as close as possible, preferring 300s, then 100s, then 50s.
+    /// For `GameMode::Mania`, perfect hits are counted as gekis, and for `GameMode::Catch`,
+    /// the amount of objects includes tiny droplets and lost accuracy is attributed to tiny droplet misses.
+    ///
+    /// # Example
+    /// ```
+    /// use rosu_v2::model::{score::ScoreStatistics, GameMode};
+    ///
+    /// let statistics = ScoreStatistics::from_accuracy(GameMode::Osu, 1000, 97.5, 3);
+    ///
+    /// assert_eq!(statistics.total_hits(GameMode::Osu), 1000);
+    /// assert_eq!(statistics.count_miss, 3);
+    /// assert_eq!(statistics.accuracy(GameMode::Osu), 97.5);
+    /// ```
+    pub fn from_accuracy(mode: GameMode, n_objects: u32, accuracy: f32, misses: u32) -> Self {
+        let misses = misses.min(n_objects);
+        let remaining = n_objects - misses;
+
+        // Weight of a perfect hit in the smallest unit of the mode's accuracy
+        let weight = match mode {
+            GameMode::Osu | GameMode::Mania => 6,
+            GameMode::Taiko => 2,
+            GameMode::Catch => 1,
+        };
+
+        let max_points = weight * remaining as i64;
+        let total_points = weight as f64 * n_objects as f64;
+        let target_points = accuracy.clamp(0.0, 100.0) as f64 / 100.0 * total_points;
+        let target = (accuracy.clamp(0.0, 100.0) * 100.0).round() / 100.0;
+
+        // Points within this distance could be rounded to the target accuracy
+        let window = total_points / 10_000.0 + 2.0;
+        let start = (target_points.round() as i64).clamp(0, max_points);
+
+        let mut closest = None;
+
+        for offset in 0.. {
+            let below = start - offset;
+            let above = start + offset;
+
+            if (below < 0 || target_points - below as f64 > window)
+                && (above > max_points || above as f64 - target_points > window)
+                && closest.is_some()
+            {
+                break;
+            }
+
+            for points in [below, above].iter().copied() {
+                if points < 0 || points > max_points {
+                    continue;
+                }
+
+                let deficit = (max_points - points) as u32;
+
+                if let Some(statistics) = Self::with_deficit(mode, remaining, misses, deficit) {
+                    if statistics.accuracy(mode) == target {
+                        return statistics;
+                    }
+
+                    closest.get_or_insert(statistics);
+                }
+            }
+
+            if below < 0 && above > max_points {
+                break;
+            }
+        }
+
+        closest.unwrap_or_else(|| Self {
+            count_miss: n_objects,
+            ..Default::default()
+        })
+    }
+
+    /// Distribute the deficit of points among the remaining hit objects
+    fn with_deficit(mode: GameMode, remaining: u32, misses: u32, deficit: u32) -> Option<Self> {
+        let mut statistics = Self {
+            count_miss: misses,
+            ..Default::default()
+        };
+
+        match mode {
+            GameMode::Osu => {
+                // A 100 loses 4 points, a 50 loses 5 points
+                let mut n50 = deficit.saturating_sub(4 * remaining);
+                n50 += (deficit - n50) % 4;
+
+                while 5 * n50 <= deficit {
+                    let n100 = (deficit - 5 * n50) / 4;
+
+                    if n100 + n50 <= remaining {
+                        statistics.count_300 = remaining - n100 - n50;
+                        statistics.count_100 = n100;
+                        statistics.count_50 = n50;
+
+                        return Some(statistics);
+                    }
+
+                    n50 += 4;
+                }
+
+                None
+            }
+            GameMode::Taiko => {
+                // A 100 loses 1 point
+                statistics.count_300 = remaining.checked_sub(deficit)?;
+                statistics.count_100 = deficit;
+
+                Some(statistics)
+            }
+            GameMode::Catch => {
+                // A tiny droplet miss loses 1 point
+                statistics.count_300 = remaining.checked_sub(deficit)?;
+                statistics.count_katu = deficit;
+
+                Some(statistics)
+            }
+            GameMode::Mania => {
+                // A 200 loses 2 points, a 100 loses 4 points, a 50 loses 5 points
+                let mut n50 = deficit.saturating_sub(4 * remaining);
+                n50 += (deficit - n50) % 2;
+
+                while 5 * n50 <= deficit {
+                    let rest = deficit - 5 * n50;
+                    let n100 = rest / 4;
+                    let n200 = (rest % 4) / 2;
+
+                    if n200 + n100 + n50 <= remaining {
+                        statistics.count_geki = remaining - n200 - n100 - n50;
+                        statistics.count_katu = n200;
+                        statistics.count_100 = n100;
+                        statistics.count_50 = n50;
+
+                        return Some(statistics);
+                    }
+
+                    n50 += 2;
+                }
+
+                None
+            }
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq)]
@@ -229,7 +464,7 @@ const HDFLFI: GameMods = GameMods::from_bits_truncate(HDFL.bits() + GameMods::Fa
 
 fn osu_grade(score: &Score, passed_objects: u32) -> Grade {
     if score.statistics.count_300 == passed_objects {
-        return if score.mods.intersects(HDFL) {
+        return if score.mods.legacy().intersects(HDFL) {
             Grade::XH
         } else {
             Grade::X
@@ -242,7 +477,7 @@ fn osu_grade(score: &Score, passed_objects: u32) -> Grade {
     let ratio50 = stats.count_50 as f32 / passed_objects as f32;
 
     if ratio300 > 0.9 && ratio50 < 0.01 && stats.count_miss == 0 {
-        if score.mods.intersects(HDFL) {
+        if score.mods.legacy().intersects(HDFL) {
             Grade::SH
         } else {
             Grade::S
@@ -260,7 +495,7 @@ fn osu_grade(score: &Score, passed_objects: u32) -> Grade {
 
 fn mania_grade(score: &Score, passed_objects: u32, accuracy: Option<f32>) -> Grade {
     if score.statistics.count_geki == passed_objects {
-        return if score.mods.intersects(HDFLFI) {
+        return if score.mods.legacy().intersects(HDFLFI) {
             Grade::XH
         } else {
             Grade::X
@@ -270,7 +505,7 @@ fn mania_grade(score: &Score, passed_objects: u32, accuracy: Option<f32>) -> Gra
     let accuracy = accuracy.unwrap_or_else(|| score.accuracy());
 
     if accuracy > 95.0 {
-        if score.mods.intersects(HDFLFI) {
+        if score.mods.legacy().intersects(HDFLFI) {
             Grade::SH
         } else {
             Grade::S
@@ -288,7 +523,7 @@ fn mania_grade(score: &Score, passed_objects: u32, accuracy: Option<f32>) -> Gra
 
 fn taiko_grade(score: &Score, passed_objects: u32) -> Grade {
     if score.statistics.count_300 == passed_objects {
-        return if score.mods.intersects(HDFL) {
+        return if score.mods.legacy().intersects(HDFL) {
             Grade::XH
         } else {
             Grade::X
@@ -299,7 +534,7 @@ fn taiko_grade(score: &Score, passed_objects: u32) -> Grade {
     let ratio300 = stats.count_300 as f32 / passed_objects as f32;
 
     if ratio300 > 0.9 && stats.count_miss == 0 {
-        if score.mods.intersects(HDFL) {
+        if score.mods.legacy().intersects(HDFL) {
             Grade::SH
         } else {
             Grade::S
@@ -318,14 +553,14 @@ fn taiko_grade(score: &Score, passed_objects: u32) -> Grade {
 fn ctb_grade(score: &Score, accuracy: Option<f32>) -> Grade {
     let accuracy = accuracy.unwrap_or_else(|| score.accuracy());
 
-    if (100.0 - accuracy).abs() <= std::f32::EPSILON {
-        if score.mods.intersects(HDFL) {
+    if (100.0 - accuracy).abs() <= f32::EPSILON {
+        if score.mods.legacy().intersects(HDFL) {
             Grade::XH
         } else {
             Grade::X
         }
     } else if accuracy > 98.0 {
-        if score.mods.intersects(HDFL) {
+        if score.mods.legacy().intersects(HDFL) {
             Grade::SH
         } else {
             Grade::S
@@ -340,3 +575,97 @@ fn ctb_grade(score: &Score, accuracy: Option<f32>) -> Grade {
         Grade::D
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Simple xorshift to get reproducible pseudo-random values
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, bound: u32) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+
+            (self.0 % bound as u64) as u32
+        }
+    }
+
+    fn random_statistics(rng: &mut Rng, mode: GameMode) -> ScoreStatistics {
+        let mut next = |bound| rng.next(bound);
+
+        let mut statistics = ScoreStatistics {
+            count_300: next(3000),
+            count_100: next(200),
+            count_miss: next(30),
+            ..Default::default()
+        };
+
+        if mode != GameMode::Taiko {
+            statistics.count_50 = next(100);
+        }
+
+        if matches!(mode, GameMode::Catch | GameMode::Mania) {
+            statistics.count_katu = next(100);
+        }
+
+        if mode == GameMode::Mania {
+            statistics.count_geki = next(3000);
+        }
+
+        statistics
+    }
+
+    #[test]
+    fn from_accuracy_is_inverse_of_accuracy() {
+        let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+
+        for mode in [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ] {
+            for _ in 0..2000 {
+                let original = random_statistics(&mut rng, mode);
+                let n_objects = original.total_hits(mode);
+
+                if n_objects == 0 {
+                    continue;
+                }
+
+                let accuracy = original.accuracy(mode);
+                let generated =
+                    ScoreStatistics::from_accuracy(mode, n_objects, accuracy, original.count_miss);
+
+                assert_eq!(generated.total_hits(mode), n_objects, "{:?}", original);
+                assert_eq!(generated.count_miss, original.count_miss, "{:?}", original);
+                assert_eq!(
+                    generated.accuracy(mode),
+                    accuracy,
+                    "{:?}: {:?} => {:?}",
+                    mode,
+                    original,
+                    generated
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_accuracy_extremes() {
+        let statistics = ScoreStatistics::from_accuracy(GameMode::Osu, 500, 100.0, 0);
+        assert_eq!(statistics.count_300, 500);
+
+        let statistics = ScoreStatistics::from_accuracy(GameMode::Osu, 500, 0.0, 0);
+        assert_eq!(statistics.count_50, 500);
+
+        let statistics = ScoreStatistics::from_accuracy(GameMode::Taiko, 500, 100.0, 500);
+        assert_eq!(statistics.count_miss, 500);
+
+        let statistics = ScoreStatistics::from_accuracy(GameMode::Mania, 0, 95.0, 0);
+        assert_eq!(statistics, ScoreStatistics::default());
+    }
+}
@@ -0,0 +1,45 @@
+use super::{user_::UserCompact, wiki_::WikiPage};
+
+use serde::Deserialize;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// The result of a site-wide search.
+///
+/// Depending on the requested mode, either or both fields will be `Some`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct SearchResult {
+    /// Users whose name matched the query
+    #[serde(default, rename = "user", skip_serializing_if = "Option::is_none")]
+    pub users: Option<UserSearchResult>,
+    /// Wiki pages whose content matched the query
+    #[serde(default, rename = "wiki_page", skip_serializing_if = "Option::is_none")]
+    pub wiki_pages: Option<WikiSearchResult>,
+}
+
+/// Users of a [`SearchResult`].
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct UserSearchResult {
+    /// The users of the requested page
+    #[serde(rename = "data")]
+    pub users: Vec<UserCompact>,
+    /// Total amount of users that matched the query
+    pub total: u32,
+}
+
+/// Wiki pages of a [`SearchResult`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct WikiSearchResult {
+    /// The wiki pages of the requested page
+    #[serde(rename = "data")]
+    pub pages: Vec<WikiPage>,
+    /// Total amount of wiki pages that matched the query
+    pub total: u32,
+}
@@ -494,6 +494,16 @@ pub struct User {
     pub medals: Option<Vec<MedalCompact>>,
 }
 
+#[derive(Deserialize)]
+pub(crate) struct BeatmapsetFavourites {
+    pub(crate) beatmapset_ids: Vec<u32>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Users {
+    pub(crate) users: Vec<UserCompact>,
+}
+
 /// Mainly used for embedding in certain responses to save additional api lookups.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -0,0 +1,320 @@
+use super::user_::{MonthlyCount, User, UserCompact};
+
+use std::convert::TryFrom;
+use time::{Date, Month, OffsetDateTime, UtcOffset};
+
+/// A user's daily rank history anchored to calendar dates.
+///
+/// The API only provides the ranks of the last 90 days without any dates so the last rank
+/// is assumed to belong to the day on which the user was fetched.
+///
+/// Ranks of zero i.e. days on which the user was unranked are kept as entries
+/// but ignored for all derived metrics.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RankHistory {
+    entries: Vec<RankHistoryEntry>,
+}
+
+/// A user's rank on a given date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RankHistoryEntry {
+    pub date: Date,
+    pub rank: u32,
+}
+
+/// A series of consecutive days on which a user's rank improved each day.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RankStreak {
+    pub start: RankHistoryEntry,
+    pub end: RankHistoryEntry,
+}
+
+impl RankStreak {
+    /// The amount of days in which the rank improved.
+    #[inline]
+    pub fn days(&self) -> u32 {
+        (self.end.date - self.start.date).whole_days() as u32
+    }
+
+    /// The amount of ranks gained throughout the streak.
+    #[inline]
+    pub fn gain(&self) -> u32 {
+        self.start.rank - self.end.rank
+    }
+}
+
+impl RankHistory {
+    /// Anchor the given ranks, ordered from oldest to newest, so that the last rank belongs to `last_date`.
+    pub fn new(ranks: &[u32], last_date: Date) -> Self {
+        let mut date = Some(last_date);
+
+        let mut entries: Vec<_> = ranks
+            .iter()
+            .rev()
+            .map_while(|&rank| {
+                let entry = RankHistoryEntry { date: date?, rank };
+                date = date.and_then(Date::previous_day);
+
+                Some(entry)
+            })
+            .collect();
+
+        entries.reverse();
+
+        Self { entries }
+    }
+
+    /// Anchor the given ranks, ordered from oldest to newest, so that the last rank belongs
+    /// to the UTC date of `fetched_at`.
+    #[inline]
+    pub fn from_fetch_time(ranks: &[u32], fetched_at: OffsetDateTime) -> Self {
+        Self::new(ranks, fetched_at.to_offset(UtcOffset::UTC).date())
+    }
+
+    /// All entries ordered from oldest to newest.
+    #[inline]
+    pub fn entries(&self) -> &[RankHistoryEntry] {
+        &self.entries
+    }
+
+    /// The most recent entry.
+    #[inline]
+    pub fn latest(&self) -> Option<RankHistoryEntry> {
+        self.entries.last().copied()
+    }
+
+    /// The entry of the given date.
+    pub fn get(&self, date: Date) -> Option<RankHistoryEntry> {
+        let first = self.entries.first()?.date;
+        let idx = (date - first).whole_days();
+
+        usize::try_from(idx)
+            .ok()
+            .and_then(|idx| self.entries.get(idx))
+            .copied()
+    }
+
+    /// The entries of the last `days` days, or all entries if `days` is `None`.
+    pub fn window(&self, days: Option<usize>) -> &[RankHistoryEntry] {
+        match days {
+            Some(days) => &self.entries[self.entries.len().saturating_sub(days)..],
+            None => &self.entries,
+        }
+    }
+
+    /// The best i.e. numerically lowest rank within the last `days` days,
+    /// or within the whole history if `days` is `None`.
+    ///
+    /// On ties, the most recent entry is returned.
+    pub fn peak(&self, days: Option<usize>) -> Option<RankHistoryEntry> {
+        ranked(self.window(days)).min_by(|a, b| a.rank.cmp(&b.rank).then(b.date.cmp(&a.date)))
+    }
+
+    /// The worst i.e. numerically highest rank within the last `days` days,
+    /// or within the whole history if `days` is `None`.
+    ///
+    /// On ties, the most recent entry is returned.
+    pub fn low(&self, days: Option<usize>) -> Option<RankHistoryEntry> {
+        ranked(self.window(days)).max_by(|a, b| a.rank.cmp(&b.rank).then(a.date.cmp(&b.date)))
+    }
+
+    /// The amount of ranks gained within the last `days` days.
+    ///
+    /// A positive value means the rank improved, a negative value means it worsened.
+    /// Returns `None` if the history does not reach back far enough or
+    /// if the user was unranked on either day.
+    pub fn delta(&self, days: usize) -> Option<i64> {
+        let latest = self.entries.last()?;
+        let idx = (self.entries.len() - 1).checked_sub(days)?;
+        let past = self.entries[idx];
+
+        if latest.rank == 0 || past.rank == 0 {
+            return None;
+        }
+
+        Some(past.rank as i64 - latest.rank as i64)
+    }
+
+    /// The amount of ranks gained within the last 7 days, see [`RankHistory::delta`].
+    #[inline]
+    pub fn delta_7d(&self) -> Option<i64> {
+        self.delta(7)
+    }
+
+    /// The amount of ranks gained within the last 30 days, see [`RankHistory::delta`].
+    #[inline]
+    pub fn delta_30d(&self) -> Option<i64> {
+        self.delta(30)
+    }
+
+    /// The longest series of consecutive days on which the rank improved each day.
+    ///
+    /// On ties, the most recent streak is returned.
+    pub fn longest_improvement_streak(&self) -> Option<RankStreak> {
+        let mut best: Option<(usize, usize)> = None;
+        let mut start = 0;
+
+        for (i, pair) in self.entries.windows(2).enumerate() {
+            let improved = pair[1].rank != 0 && pair[1].rank < pair[0].rank;
+
+            if !improved {
+                start = i + 1;
+
+                continue;
+            }
+
+            let len = i + 1 - start;
+
+            match best {
+                Some((best_start, best_end)) if len < best_end - best_start => {}
+                _ => best = Some((start, i + 1)),
+            }
+        }
+
+        best.map(|(start, end)| RankStreak {
+            start: self.entries[start],
+            end: self.entries[end],
+        })
+    }
+}
+
+fn ranked(entries: &[RankHistoryEntry]) -> impl Iterator<Item = RankHistoryEntry> + '_ {
+    entries.iter().copied().filter(|entry| entry.rank > 0)
+}
+
+impl MonthlyCount {
+    /// Sort the counts by date and insert a count of zero for each missing month
+    /// between the first and the last one.
+    pub fn fill_gaps(counts: &[MonthlyCount]) -> Vec<MonthlyCount> {
+        let mut sorted = counts.to_vec();
+        sorted.sort_unstable_by_key(|count| count.start_date);
+
+        let mut filled = Vec::with_capacity(sorted.len());
+        let mut iter = sorted.into_iter();
+
+        let mut prev = match iter.next() {
+            Some(count) => count,
+            None => return filled,
+        };
+
+        filled.push(prev);
+
+        for count in iter {
+            let mut next = next_month(prev.start_date);
+
+            while let Some(start_date) = next.filter(|date| *date < count.start_date) {
+                filled.push(MonthlyCount {
+                    start_date,
+                    count: 0,
+                });
+
+                next = next_month(start_date);
+            }
+
+            filled.push(count);
+            prev = count;
+        }
+
+        filled
+    }
+}
+
+fn next_month(date: Date) -> Option<Date> {
+    let (year, month) = match date.month() {
+        Month::December => (date.year() + 1, Month::January),
+        month => (date.year(), month.next()),
+    };
+
+    Date::from_calendar_date(year, month, 1).ok()
+}
+
+macro_rules! impl_history {
+    ($ty:ident) => {
+        impl $ty {
+            /// The user's rank history anchored to dates, assuming the user was fetched at `fetched_at`.
+            ///
+            /// Returns `None` if the rank history was not included in the response.
+            #[inline]
+            pub fn dated_rank_history(&self, fetched_at: OffsetDateTime) -> Option<RankHistory> {
+                self.rank_history
+                    .as_deref()
+                    .map(|ranks| RankHistory::from_fetch_time(ranks, fetched_at))
+            }
+
+            /// The user's monthly playcounts including a count of zero for inactive months.
+            ///
+            /// See [`MonthlyCount::fill_gaps`].
+            #[inline]
+            pub fn monthly_playcounts_filled(&self) -> Option<Vec<MonthlyCount>> {
+                self.monthly_playcounts
+                    .as_deref()
+                    .map(MonthlyCount::fill_gaps)
+            }
+
+            /// The user's monthly replays watched counts including a count of zero for inactive months.
+            ///
+            /// See [`MonthlyCount::fill_gaps`].
+            #[inline]
+            pub fn replays_watched_counts_filled(&self) -> Option<Vec<MonthlyCount>> {
+                self.replays_watched_counts
+                    .as_deref()
+                    .map(MonthlyCount::fill_gaps)
+            }
+        }
+    };
+}
+
+impl_history!(User);
+impl_history!(UserCompact);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn rank_history_metrics() {
+        let ranks = [500, 0, 480, 470, 460, 465, 450, 440, 430, 420, 425];
+        let history = RankHistory::new(&ranks, date(2023, Month::March, 2));
+
+        assert_eq!(history.entries()[0].date, date(2023, Month::February, 20));
+        assert_eq!(
+            history.get(date(2023, Month::February, 28)).unwrap().rank,
+            430
+        );
+
+        assert_eq!(history.peak(None).unwrap().rank, 420);
+        assert_eq!(history.low(None).unwrap().rank, 500);
+        assert_eq!(history.low(Some(3)).unwrap().rank, 430);
+        assert_eq!(history.delta_7d(), Some(470 - 425));
+        assert_eq!(history.delta_30d(), None);
+
+        let streak = history.longest_improvement_streak().unwrap();
+        assert_eq!(streak.start.rank, 465);
+        assert_eq!(streak.end.rank, 420);
+        assert_eq!(streak.days(), 4);
+    }
+
+    #[test]
+    fn monthly_count_gaps() {
+        let counts = [
+            MonthlyCount {
+                start_date: date(2023, Month::February, 1),
+                count: 3,
+            },
+            MonthlyCount {
+                start_date: date(2022, Month::November, 1),
+                count: 5,
+            },
+        ];
+
+        let filled = MonthlyCount::fill_gaps(&counts);
+        let counts: Vec<_> = filled.iter().map(|count| count.count).collect();
+
+        assert_eq!(counts, [5, 0, 0, 3]);
+        assert_eq!(filled[2].start_date, date(2023, Month::January, 1));
+    }
+}
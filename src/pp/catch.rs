@@ -0,0 +1,70 @@
+use crate::model::{score::ScoreStatistics, GameMods};
+
+pub(super) fn performance(
+    stars: f64,
+    ar: f64,
+    max_combo: u32,
+    mods: GameMods,
+    statistics: &ScoreStatistics,
+    combo: u32,
+) -> f64 {
+    // Fruits are 300s, droplets are 100s, tiny droplets are 50s, and tiny droplet misses are katus
+    let combo_hits = (statistics.count_300 + statistics.count_100 + statistics.count_miss) as f64;
+    let total = combo_hits + (statistics.count_50 + statistics.count_katu) as f64;
+
+    if total <= 0.0 {
+        return 0.0;
+    }
+
+    let hits = (statistics.count_300 + statistics.count_100 + statistics.count_50) as f64;
+    let acc = hits / total;
+
+    let mut pp = (5.0 * (stars / 0.0049).max(1.0) - 4.0).powi(2) / 100_000.0;
+
+    let mut len_bonus = 0.95 + 0.3 * (combo_hits / 2500.0).min(1.0);
+
+    if combo_hits > 2500.0 {
+        len_bonus += (combo_hits / 2500.0).log10() * 0.475;
+    }
+
+    pp *= len_bonus;
+    pp *= 0.97_f64.powi(statistics.count_miss as i32);
+
+    if max_combo > 0 {
+        pp *= ((combo as f64).powf(0.8) / (max_combo as f64).powf(0.8)).min(1.0);
+    }
+
+    let mut ar_factor = 1.0;
+
+    if ar > 9.0 {
+        ar_factor += 0.1 * (ar - 9.0);
+    }
+
+    if ar > 10.0 {
+        ar_factor += 0.1 * (ar - 10.0);
+    } else if ar < 8.0 {
+        ar_factor += 0.025 * (8.0 - ar);
+    }
+
+    pp *= ar_factor;
+
+    if mods.contains(GameMods::Hidden) {
+        if ar <= 10.0 {
+            pp *= 1.05 + 0.075 * (10.0 - ar);
+        } else {
+            pp *= 1.01 + 0.04 * (11.0 - ar.min(11.0));
+        }
+    }
+
+    if mods.contains(GameMods::Flashlight) {
+        pp *= 1.35 * len_bonus;
+    }
+
+    pp *= acc.powf(5.5);
+
+    if mods.contains(GameMods::NoFail) {
+        pp *= 0.9;
+    }
+
+    pp
+}
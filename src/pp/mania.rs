@@ -0,0 +1,33 @@
+use crate::model::{score::ScoreStatistics, GameMode, GameMods};
+
+pub(super) fn performance(stars: f64, mods: GameMods, statistics: &ScoreStatistics) -> f64 {
+    let total_hits = statistics.total_hits(GameMode::Mania) as f64;
+
+    if total_hits <= 0.0 {
+        return 0.0;
+    }
+
+    // Gekis are 320s and katus are 200s
+    let custom_acc = (statistics.count_geki * 32
+        + statistics.count_300 * 30
+        + statistics.count_katu * 20
+        + statistics.count_100 * 10
+        + statistics.count_50 * 5) as f64
+        / (total_hits * 32.0);
+
+    let mut multiplier = 8.0;
+
+    if mods.contains(GameMods::NoFail) {
+        multiplier *= 0.75;
+    }
+
+    if mods.contains(GameMods::Easy) {
+        multiplier *= 0.5;
+    }
+
+    let difficulty = (stars - 0.15).max(0.05).powf(2.2)
+        * (5.0 * custom_acc - 4.0).max(0.0)
+        * (1.0 + 0.1 * (total_hits / 1500.0).min(1.0));
+
+    difficulty * multiplier
+}
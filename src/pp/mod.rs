@@ -0,0 +1,409 @@
+mod catch;
+mod mania;
+mod osu;
+mod profile;
+mod taiko;
+
+pub use profile::{ProfilePp, MAX_BONUS_PP};
+
+use crate::model::{
+    beatmap::{Beatmap, BeatmapDifficultyAttributes, GameModeAttributes},
+    score::{Score, ScoreStatistics},
+    GameMode, GameMods,
+};
+
+/// Calculate the performance points of a score based on
+/// the [`BeatmapDifficultyAttributes`] of its map.
+///
+/// The score can be specified through hit results, accuracy, misses, and combo
+/// which makes it possible to calculate pp for hypothetical scores.
+///
+/// Note that the difficulty attributes must have been requested for the same mods
+/// that are used for the calculation.
+///
+/// # Example
+/// ```no_run
+/// use rosu_v2::{model::GameMods, pp::Performance, Osu};
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let mods = GameMods::Hidden | GameMods::DoubleTime;
+/// let map = osu.beatmap().map_id(1851299).await?;
+/// let attrs = osu.beatmap_difficulty_attributes(1851299).mods(mods).await?;
+///
+/// // 98.5% accuracy with 2 misses and a combo of 1000
+/// let pp = Performance::new(&attrs)
+///     .map(&map)
+///     .mods(mods)
+///     .accuracy(98.5)
+///     .misses(2)
+///     .combo(1000)
+///     .calculate();
+///
+/// // The same but without the misses
+/// let fc_pp = Performance::new(&attrs)
+///     .map(&map)
+///     .mods(mods)
+///     .accuracy(98.5)
+///     .fc()
+///     .calculate();
+/// # Ok::<_, rosu_v2::error::OsuError>(()) };
+/// ```
+#[derive(Clone, Debug)]
+pub struct Performance<'a> {
+    attrs: &'a BeatmapDifficultyAttributes,
+    mods: GameMods,
+    combo: Option<u32>,
+    statistics: Option<ScoreStatistics>,
+    accuracy: Option<f32>,
+    misses: Option<u32>,
+    objects: Option<ObjectCounts>,
+    fc: bool,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct ObjectCounts {
+    circles: u32,
+    sliders: u32,
+    spinners: u32,
+}
+
+impl<'a> Performance<'a> {
+    /// Create a new performance calculation based on the given attributes.
+    ///
+    /// Unless specified otherwise, the score is assumed to be an SS.
+    #[inline]
+    pub fn new(attrs: &'a BeatmapDifficultyAttributes) -> Self {
+        Self {
+            attrs,
+            mods: GameMods::NoMod,
+            combo: None,
+            statistics: None,
+            accuracy: None,
+            misses: None,
+            objects: None,
+            fc: false,
+        }
+    }
+
+    /// Specify the mods.
+    #[inline]
+    pub fn mods(mut self, mods: GameMods) -> Self {
+        self.mods = mods;
+
+        self
+    }
+
+    /// Specify the max combo of the score, defaults to the map's max combo.
+    #[inline]
+    pub fn combo(mut self, combo: u32) -> Self {
+        self.combo = Some(combo);
+
+        self
+    }
+
+    /// Specify the hit results of the score.
+    #[inline]
+    pub fn statistics(mut self, statistics: ScoreStatistics) -> Self {
+        self.statistics = Some(statistics);
+
+        self
+    }
+
+    /// Specify the accuracy i.e. `0 <= accuracy <= 100`.
+    ///
+    /// Hit results will be generated to match the accuracy as close as possible.
+    /// If hit results were specified, their total amount is kept.
+    #[inline]
+    pub fn accuracy(mut self, accuracy: f32) -> Self {
+        self.accuracy = Some(accuracy.clamp(0.0, 100.0));
+
+        self
+    }
+
+    /// Specify the amount of misses.
+    #[inline]
+    pub fn misses(mut self, misses: u32) -> Self {
+        self.misses = Some(misses);
+
+        self
+    }
+
+    /// Specify the map to get its amount of hit objects from.
+    ///
+    /// Without the map or hit results, the amount of hit objects
+    /// can only be estimated through the max combo.
+    #[inline]
+    pub fn map(mut self, map: &Beatmap) -> Self {
+        self.objects = Some(ObjectCounts {
+            circles: map.count_circles,
+            sliders: map.count_sliders,
+            spinners: map.count_spinners,
+        });
+
+        self
+    }
+
+    /// Use the mods, combo, and hit results of the given score.
+    ///
+    /// If the score contains its map, the map's amount of hit objects are used too.
+    pub fn score(mut self, score: &Score) -> Self {
+        self.mods = score.mods.legacy();
+        self.combo = Some(score.max_combo);
+        self.statistics = Some(score.statistics.clone());
+
+        match score.map {
+            Some(ref map) => self.map(map),
+            None => self,
+        }
+    }
+
+    /// Consider the score to be a full combo i.e. misses are
+    /// turned into 300s and the combo is the map's max combo.
+    #[inline]
+    pub fn fc(mut self) -> Self {
+        self.fc = true;
+
+        self
+    }
+
+    /// Calculate the performance points.
+    pub fn calculate(&self) -> f32 {
+        let mode = self.mode();
+        let statistics = self.resolve_statistics(mode);
+
+        let combo = match self.combo {
+            Some(combo) if !self.fc => combo.min(self.attrs.max_combo),
+            _ => self.attrs.max_combo,
+        };
+
+        let stars = self.attrs.stars as f64;
+        let max_combo = self.attrs.max_combo;
+        let mods = self.mods;
+
+        let pp = match self.attrs.attrs {
+            GameModeAttributes::Osu {
+                ar,
+                od,
+                aim_difficulty,
+                flashlight_difficulty,
+                slider_factor,
+                speed_difficulty,
+            } => {
+                let total_hits = statistics.total_hits(GameMode::Osu);
+
+                let objects = self.objects.unwrap_or(ObjectCounts {
+                    circles: total_hits,
+                    sliders: 0,
+                    spinners: 0,
+                });
+
+                let attrs = osu::OsuAttributes {
+                    ar: ar as f64,
+                    od: od as f64,
+                    aim: aim_difficulty as f64,
+                    speed: speed_difficulty as f64,
+                    flashlight: flashlight_difficulty as f64,
+                    slider_factor: slider_factor as f64,
+                    max_combo,
+                    objects,
+                };
+
+                osu::performance(&attrs, mods, &statistics, combo)
+            }
+            GameModeAttributes::Taiko {
+                great_hit_window, ..
+            } => taiko::performance(stars, great_hit_window as f64, mods, &statistics),
+            GameModeAttributes::Catch { ar } => {
+                catch::performance(stars, ar as f64, max_combo, mods, &statistics, combo)
+            }
+            GameModeAttributes::Mania { .. } => mania::performance(stars, mods, &statistics),
+        };
+
+        pp as f32
+    }
+
+    fn mode(&self) -> GameMode {
+        match self.attrs.attrs {
+            GameModeAttributes::Osu { .. } => GameMode::Osu,
+            GameModeAttributes::Taiko { .. } => GameMode::Taiko,
+            GameModeAttributes::Catch { .. } => GameMode::Catch,
+            GameModeAttributes::Mania { .. } => GameMode::Mania,
+        }
+    }
+
+    fn n_objects(&self, mode: GameMode) -> u32 {
+        match (mode, self.objects) {
+            (GameMode::Osu, Some(objects)) => objects.circles + objects.sliders + objects.spinners,
+            (GameMode::Mania, Some(objects)) => objects.circles + objects.sliders,
+            _ => self.attrs.max_combo,
+        }
+    }
+
+    fn resolve_statistics(&self, mode: GameMode) -> ScoreStatistics {
+        let total = match self.statistics {
+            Some(ref statistics) => statistics.total_hits(mode),
+            None => self.n_objects(mode),
+        };
+
+        let misses = if self.fc {
+            0
+        } else {
+            self.misses
+                .or_else(|| self.statistics.as_ref().map(|s| s.count_miss))
+                .unwrap_or(0)
+                .min(total)
+        };
+
+        match (self.statistics.as_ref(), self.accuracy) {
+            (Some(statistics), None) => with_misses(mode, statistics, misses),
+            (statistics, accuracy) => {
+                generate_statistics(mode, total, accuracy.unwrap_or(100.0), misses, statistics)
+            }
+        }
+    }
+}
+
+impl BeatmapDifficultyAttributes {
+    /// Start a [`Performance`] calculation based on these attributes.
+    #[inline]
+    pub fn performance(&self) -> Performance<'_> {
+        Performance::new(self)
+    }
+}
+
+/// Adjust the amount of misses by turning misses into 300s or vice versa
+fn with_misses(mode: GameMode, statistics: &ScoreStatistics, misses: u32) -> ScoreStatistics {
+    let mut statistics = statistics.clone();
+
+    let perfect = match mode {
+        GameMode::Mania => &mut statistics.count_geki,
+        GameMode::Osu | GameMode::Taiko | GameMode::Catch => &mut statistics.count_300,
+    };
+
+    if misses < statistics.count_miss {
+        *perfect += statistics.count_miss - misses;
+        statistics.count_miss = misses;
+    } else {
+        let diff = (misses - statistics.count_miss).min(*perfect);
+        *perfect -= diff;
+        statistics.count_miss += diff;
+    }
+
+    statistics
+}
+
+/// Generate hit results for the given amount of objects that match the accuracy as close as possible.
+///
+/// For osu!catch, the amount of fruits, droplets, and tiny droplets of the base are kept.
+fn generate_statistics(
+    mode: GameMode,
+    total: u32,
+    accuracy: f32,
+    misses: u32,
+    base: Option<&ScoreStatistics>,
+) -> ScoreStatistics {
+    let base = match (mode, base) {
+        (GameMode::Catch, Some(base)) => base,
+        _ => return ScoreStatistics::from_accuracy(mode, total, accuracy, misses),
+    };
+
+    let combo_objects = base.count_300 + base.count_100 + base.count_miss;
+    let tiny_droplets = base.count_50 + base.count_katu;
+
+    let misses = misses.min(combo_objects);
+    let hits = combo_objects - misses;
+    let droplets = base.count_100.min(hits);
+
+    let target = (accuracy as f64 / 100.0 * (combo_objects + tiny_droplets) as f64).round() as u32;
+    let tiny_hits = target.saturating_sub(hits).min(tiny_droplets);
+
+    ScoreStatistics {
+        count_300: hits - droplets,
+        count_100: droplets,
+        count_50: tiny_hits,
+        count_katu: tiny_droplets - tiny_hits,
+        count_miss: misses,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn osu_attrs() -> BeatmapDifficultyAttributes {
+        BeatmapDifficultyAttributes {
+            max_combo: 909,
+            stars: 5.6,
+            attrs: GameModeAttributes::Osu {
+                ar: 9.0,
+                od: 8.5,
+                aim_difficulty: 2.8,
+                flashlight_difficulty: 2.0,
+                slider_factor: 0.98,
+                speed_difficulty: 2.5,
+            },
+        }
+    }
+
+    #[test]
+    fn osu_pp_decreases_with_worse_scores() {
+        let attrs = osu_attrs();
+
+        let ss = attrs.performance().calculate();
+        let acc = attrs.performance().accuracy(95.0).calculate();
+        let misses = attrs
+            .performance()
+            .accuracy(95.0)
+            .misses(5)
+            .combo(400)
+            .calculate();
+        let fc = attrs
+            .performance()
+            .accuracy(95.0)
+            .misses(5)
+            .combo(400)
+            .fc()
+            .calculate();
+
+        assert!(ss > acc, "{} <= {}", ss, acc);
+        assert!(acc > misses, "{} <= {}", acc, misses);
+        assert!((fc - acc).abs() < f32::EPSILON, "{} != {}", fc, acc);
+    }
+
+    #[test]
+    fn generated_statistics_match_accuracy() {
+        for mode in [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ] {
+            let statistics = generate_statistics(mode, 1000, 97.5, 3, None);
+
+            assert_eq!(statistics.total_hits(mode), 1000, "{:?}", mode);
+            assert_eq!(statistics.count_miss, 3, "{:?}", mode);
+            assert!((statistics.accuracy(mode) - 97.5).abs() < 0.1, "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn catch_statistics_keep_structure() {
+        let base = ScoreStatistics {
+            count_300: 500,
+            count_100: 40,
+            count_50: 300,
+            count_katu: 10,
+            count_miss: 2,
+            ..Default::default()
+        };
+
+        let statistics = generate_statistics(GameMode::Catch, 852, 97.0, 0, Some(&base));
+
+        assert_eq!(statistics.count_300, 502);
+        assert_eq!(statistics.count_100, 40);
+        assert_eq!(statistics.count_50 + statistics.count_katu, 310);
+        assert!((statistics.accuracy(GameMode::Catch) - 97.0).abs() < 0.1);
+    }
+}
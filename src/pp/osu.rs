@@ -0,0 +1,279 @@
+use super::ObjectCounts;
+use crate::model::{score::ScoreStatistics, GameMode, GameMods};
+
+pub(super) struct OsuAttributes {
+    pub(super) ar: f64,
+    pub(super) od: f64,
+    pub(super) aim: f64,
+    pub(super) speed: f64,
+    pub(super) flashlight: f64,
+    pub(super) slider_factor: f64,
+    pub(super) max_combo: u32,
+    pub(super) objects: ObjectCounts,
+}
+
+struct OsuScore<'s> {
+    statistics: &'s ScoreStatistics,
+    combo: u32,
+    total_hits: f64,
+    acc: f64,
+    effective_misses: f64,
+}
+
+pub(super) fn performance(
+    attrs: &OsuAttributes,
+    mods: GameMods,
+    statistics: &ScoreStatistics,
+    combo: u32,
+) -> f64 {
+    let total_hits = statistics.total_hits(GameMode::Osu) as f64;
+
+    if total_hits <= 0.0 {
+        return 0.0;
+    }
+
+    let mut score = OsuScore {
+        statistics,
+        combo,
+        total_hits,
+        acc: (6 * statistics.count_300 + 2 * statistics.count_100 + statistics.count_50) as f64
+            / (6.0 * total_hits),
+        effective_misses: effective_miss_count(attrs, statistics, combo),
+    };
+
+    let mut multiplier = 1.14;
+
+    if mods.contains(GameMods::NoFail) {
+        multiplier *= (1.0 - 0.02 * score.effective_misses).max(0.9);
+    }
+
+    if mods.contains(GameMods::SpunOut) {
+        multiplier *= 1.0 - (attrs.objects.spinners as f64 / total_hits).powf(0.85);
+    }
+
+    if mods.contains(GameMods::Relax) {
+        let ok_multiplier = if attrs.od > 0.0 {
+            1.0 - (attrs.od / 13.33).powf(1.8)
+        } else {
+            1.0
+        };
+
+        let meh_multiplier = if attrs.od > 0.0 {
+            1.0 - (attrs.od / 13.33).powi(5)
+        } else {
+            1.0
+        };
+
+        score.effective_misses = (score.effective_misses
+            + statistics.count_100 as f64 * ok_multiplier
+            + statistics.count_50 as f64 * meh_multiplier)
+            .min(total_hits);
+    }
+
+    let aim = aim_value(attrs, mods, &score);
+    let speed = speed_value(attrs, mods, &score);
+    let acc = accuracy_value(attrs, mods, &score);
+    let flashlight = flashlight_value(attrs, mods, &score);
+
+    (aim.powf(1.1) + speed.powf(1.1) + acc.powf(1.1) + flashlight.powf(1.1)).powf(1.0 / 1.1)
+        * multiplier
+}
+
+fn effective_miss_count(attrs: &OsuAttributes, statistics: &ScoreStatistics, combo: u32) -> f64 {
+    let sliders = attrs.objects.sliders as f64;
+    let mut combo_based_misses = 0.0;
+
+    if sliders > 0.0 {
+        let full_combo_threshold = attrs.max_combo as f64 - 0.1 * sliders;
+
+        if (combo as f64) < full_combo_threshold {
+            combo_based_misses = full_combo_threshold / (combo as f64).max(1.0);
+        }
+    }
+
+    let breaks = statistics.count_100 + statistics.count_50 + statistics.count_miss;
+
+    combo_based_misses
+        .min(breaks as f64)
+        .max(statistics.count_miss as f64)
+}
+
+fn length_bonus(total_hits: f64) -> f64 {
+    let mut bonus = 0.95 + 0.4 * (total_hits / 2000.0).min(1.0);
+
+    if total_hits > 2000.0 {
+        bonus += (total_hits / 2000.0).log10() * 0.5;
+    }
+
+    bonus
+}
+
+fn combo_scaling(attrs: &OsuAttributes, score: &OsuScore<'_>) -> f64 {
+    if attrs.max_combo == 0 {
+        1.0
+    } else {
+        ((score.combo as f64).powf(0.8) / (attrs.max_combo as f64).powf(0.8)).min(1.0)
+    }
+}
+
+fn aim_value(attrs: &OsuAttributes, mods: GameMods, score: &OsuScore<'_>) -> f64 {
+    let mut aim = (5.0 * (attrs.aim / 0.0675).max(1.0) - 4.0).powi(3) / 100_000.0;
+
+    let len_bonus = length_bonus(score.total_hits);
+    aim *= len_bonus;
+
+    if score.effective_misses > 0.0 {
+        aim *= 0.97
+            * (1.0 - (score.effective_misses / score.total_hits).powf(0.775))
+                .powf(score.effective_misses);
+    }
+
+    aim *= combo_scaling(attrs, score);
+
+    let ar_factor = if mods.contains(GameMods::Relax) {
+        0.0
+    } else if attrs.ar > 10.33 {
+        0.3 * (attrs.ar - 10.33)
+    } else if attrs.ar < 8.0 {
+        0.05 * (8.0 - attrs.ar)
+    } else {
+        0.0
+    };
+
+    aim *= 1.0 + ar_factor * len_bonus;
+
+    if mods.contains(GameMods::Hidden) {
+        aim *= 1.0 + 0.04 * (12.0 - attrs.ar);
+    }
+
+    // Assume 15% of sliders to be difficult
+    let difficult_sliders = attrs.objects.sliders as f64 * 0.15;
+
+    if difficult_sliders > 0.0 {
+        let statistics = score.statistics;
+        let breaks = statistics.count_100 + statistics.count_50 + statistics.count_miss;
+        let dropped_slider_ends = (breaks.min(attrs.max_combo.saturating_sub(score.combo)) as f64)
+            .clamp(0.0, difficult_sliders);
+
+        let slider_nerf_factor = (1.0 - attrs.slider_factor)
+            * (1.0 - dropped_slider_ends / difficult_sliders).powi(3)
+            + attrs.slider_factor;
+
+        aim *= slider_nerf_factor;
+    }
+
+    aim *= score.acc;
+    aim *= 0.98 + attrs.od * attrs.od / 2500.0;
+
+    aim
+}
+
+fn speed_value(attrs: &OsuAttributes, mods: GameMods, score: &OsuScore<'_>) -> f64 {
+    if mods.contains(GameMods::Relax) {
+        return 0.0;
+    }
+
+    let mut speed = (5.0 * (attrs.speed / 0.0675).max(1.0) - 4.0).powi(3) / 100_000.0;
+
+    let len_bonus = length_bonus(score.total_hits);
+    speed *= len_bonus;
+
+    if score.effective_misses > 0.0 {
+        speed *= 0.97
+            * (1.0 - (score.effective_misses / score.total_hits).powf(0.775))
+                .powf(score.effective_misses.powf(0.875));
+    }
+
+    speed *= combo_scaling(attrs, score);
+
+    let ar_factor = if attrs.ar > 10.33 {
+        0.3 * (attrs.ar - 10.33)
+    } else {
+        0.0
+    };
+
+    speed *= 1.0 + ar_factor * len_bonus;
+
+    if mods.contains(GameMods::Hidden) {
+        speed *= 1.0 + 0.04 * (12.0 - attrs.ar);
+    }
+
+    speed *=
+        (0.95 + attrs.od * attrs.od / 750.0) * score.acc.powf((14.5 - attrs.od.max(8.0)) / 2.0);
+
+    // Punish doubletapping
+    let n50 = score.statistics.count_50 as f64;
+    let threshold = score.total_hits / 500.0;
+
+    if n50 >= threshold {
+        speed *= 0.98_f64.powf(n50 - threshold);
+    }
+
+    speed
+}
+
+fn accuracy_value(attrs: &OsuAttributes, mods: GameMods, score: &OsuScore<'_>) -> f64 {
+    if mods.contains(GameMods::Relax) {
+        return 0.0;
+    }
+
+    let statistics = score.statistics;
+
+    let objects_with_acc = if mods.contains(GameMods::ScoreV2) {
+        score.total_hits
+    } else {
+        attrs.objects.circles as f64
+    };
+
+    let better_acc = if objects_with_acc > 0.0 {
+        let n300 = statistics.count_300 as f64 - (score.total_hits - objects_with_acc);
+
+        ((n300 * 6.0 + statistics.count_100 as f64 * 2.0 + statistics.count_50 as f64)
+            / (objects_with_acc * 6.0))
+            .max(0.0)
+    } else {
+        0.0
+    };
+
+    let mut acc = 1.52163_f64.powf(attrs.od) * better_acc.powi(24) * 2.83;
+    acc *= (objects_with_acc / 1000.0).powf(0.3).min(1.15);
+
+    if mods.contains(GameMods::Hidden) {
+        acc *= 1.08;
+    }
+
+    if mods.contains(GameMods::Flashlight) {
+        acc *= 1.02;
+    }
+
+    acc
+}
+
+fn flashlight_value(attrs: &OsuAttributes, mods: GameMods, score: &OsuScore<'_>) -> f64 {
+    if !mods.contains(GameMods::Flashlight) {
+        return 0.0;
+    }
+
+    let mut flashlight = attrs.flashlight * attrs.flashlight * 25.0;
+
+    if score.effective_misses > 0.0 {
+        flashlight *= 0.97
+            * (1.0 - (score.effective_misses / score.total_hits).powf(0.775))
+                .powf(score.effective_misses.powf(0.875));
+    }
+
+    flashlight *= combo_scaling(attrs, score);
+
+    let mut len_factor = 0.7 + 0.1 * (score.total_hits / 200.0).min(1.0);
+
+    if score.total_hits > 200.0 {
+        len_factor += 0.2 * ((score.total_hits - 200.0) / 200.0).min(1.0);
+    }
+
+    flashlight *= len_factor;
+
+    flashlight *= 0.5 + score.acc / 2.0;
+    flashlight *= 0.98 + attrs.od * attrs.od / 2500.0;
+
+    flashlight
+}
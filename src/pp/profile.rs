@@ -0,0 +1,207 @@
+use crate::model::{score::Score, user::UserStatistics};
+
+/// The maximum amount of bonus pp a user can have
+pub const MAX_BONUS_PP: f32 = 416.6667;
+
+/// The amount of top scores that are considered for a user's weighted pp
+const TOP_SCORES: usize = 100;
+
+/// Analytics on the pp of a user's top scores as provided by [`GetUserScores::best`](crate::request::GetUserScores::best).
+///
+/// Each score's pp is weighted by `0.95^i` where `i` is its index in the top scores.
+/// On top of the weighted sum, a user receives bonus pp based on their amount of ranked scores.
+///
+/// # Example
+/// ```no_run
+/// use rosu_v2::{pp::ProfilePp, Osu};
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let user = osu.user("badewanne3").await?;
+/// let scores = osu.user_scores("badewanne3").best().limit(100).await?;
+///
+/// let profile = ProfilePp::new(&scores).user_pp(user.statistics.as_ref().map_or(0.0, |s| s.pp));
+///
+/// println!("Bonus pp: {:.2}", profile.bonus_pp());
+/// println!("Total with a new 400pp score: {:.2}", profile.total_with(400.0));
+/// println!("Raw pp required for +10pp: {:.2}", profile.raw_pp_needed(10.0));
+/// # Ok::<_, rosu_v2::error::OsuError>(()) };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProfilePp {
+    pps: Vec<f32>,
+    user_pp: Option<f32>,
+}
+
+impl ProfilePp {
+    /// Create a new [`ProfilePp`] from a user's top scores.
+    ///
+    /// If a score's pp is missing, it is derived from its [`ScoreWeight`](crate::model::score::ScoreWeight).
+    /// Scores without either are ignored.
+    pub fn new(scores: &[Score]) -> Self {
+        let pps = scores.iter().filter_map(|score| {
+            score.pp.or_else(|| {
+                score
+                    .weight
+                    .as_ref()
+                    .filter(|weight| weight.percentage > 0.0)
+                    .map(|weight| weight.pp * 100.0 / weight.percentage)
+            })
+        });
+
+        Self::from_pps(pps)
+    }
+
+    /// Create a new [`ProfilePp`] from raw pp values of top scores in any order.
+    pub fn from_pps<I: IntoIterator<Item = f32>>(pps: I) -> Self {
+        let mut pps: Vec<_> = pps.into_iter().filter(|pp| pp.is_finite()).collect();
+        pps.sort_unstable_by(|a, b| b.total_cmp(a));
+        pps.truncate(TOP_SCORES);
+
+        Self { pps, user_pp: None }
+    }
+
+    /// Specify the user's total pp e.g. [`UserStatistics::pp`].
+    ///
+    /// Required to consider bonus pp, otherwise the weighted sum is assumed to be the total.
+    #[inline]
+    pub fn user_pp(mut self, pp: f32) -> Self {
+        self.user_pp = Some(pp);
+
+        self
+    }
+
+    /// Use the total pp of the given statistics, see [`ProfilePp::user_pp`].
+    #[inline]
+    pub fn statistics(self, statistics: &UserStatistics) -> Self {
+        self.user_pp(statistics.pp)
+    }
+
+    /// The raw pp values of the top scores, sorted in descending order.
+    #[inline]
+    pub fn pps(&self) -> &[f32] {
+        &self.pps
+    }
+
+    /// The sum of all weighted pp values.
+    pub fn weighted_sum(&self) -> f32 {
+        weighted_sum(self.pps.iter().copied())
+    }
+
+    /// The estimated bonus pp, i.e. the difference between the user's total pp and the weighted sum.
+    ///
+    /// Without [`ProfilePp::user_pp`] this is always zero.
+    pub fn bonus_pp(&self) -> f32 {
+        self.user_pp.map_or(0.0, |total| {
+            (total - self.weighted_sum()).clamp(0.0, MAX_BONUS_PP)
+        })
+    }
+
+    /// The user's total pp, or the weighted sum if the total was not specified.
+    #[inline]
+    pub fn total(&self) -> f32 {
+        self.user_pp.unwrap_or_else(|| self.weighted_sum())
+    }
+
+    /// The total pp after adding a score with the given raw pp.
+    ///
+    /// If there are already 100 top scores, the lowest one is dropped.
+    pub fn total_with(&self, pp: f32) -> f32 {
+        self.total() + self.gain(pp)
+    }
+
+    /// The amount of total pp that would be gained by adding a score with the given raw pp.
+    pub fn gain(&self, pp: f32) -> f32 {
+        let idx = self.pps.partition_point(|&other| other >= pp);
+
+        if idx >= TOP_SCORES {
+            return 0.0;
+        }
+
+        let end = self.pps.len().min(TOP_SCORES - 1);
+
+        // Scores after the new score are weighted one index further
+        // and the last one might drop out of the top scores
+        let shifted: f32 = self.pps[idx..]
+            .iter()
+            .enumerate()
+            .map(|(i, &pp)| {
+                let weight = 0.95_f32.powi((idx + i) as i32);
+                let new_weight = if idx + i < end { weight * 0.95 } else { 0.0 };
+
+                pp * (new_weight - weight)
+            })
+            .sum();
+
+        pp * 0.95_f32.powi(idx as i32) + shifted
+    }
+
+    /// The raw pp a new score must have so that the total pp increases by the given amount.
+    ///
+    /// Returns `f32::INFINITY` if the gain cannot be reached.
+    pub fn raw_pp_needed(&self, gain: f32) -> f32 {
+        if gain <= 0.0 {
+            return 0.0;
+        }
+
+        // A new top score of `gain + weighted_sum` always gains at least `gain`
+        let mut high = gain + self.weighted_sum();
+        let mut low = 0.0_f32;
+
+        if self.gain(high) < gain {
+            return f32::INFINITY;
+        }
+
+        for _ in 0..64 {
+            let mid = (low + high) / 2.0;
+
+            if self.gain(mid) >= gain {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        high
+    }
+}
+
+fn weighted_sum<I: Iterator<Item = f32>>(pps: I) -> f32 {
+    pps.zip(0..).map(|(pp, i)| pp * 0.95_f32.powi(i)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gain_matches_recalculation() {
+        let pps: Vec<f32> = (0..100).map(|i| 500.0 - i as f32 * 3.0).collect();
+        let profile = ProfilePp::from_pps(pps.clone()).user_pp(9000.0);
+
+        for &new in &[600.0, 450.0, 210.0, 100.0] {
+            let mut expected = pps.clone();
+            expected.push(new);
+            expected.sort_unstable_by(|a, b| b.total_cmp(a));
+            expected.truncate(100);
+
+            let diff = weighted_sum(expected.into_iter()) - profile.weighted_sum();
+
+            assert!((profile.gain(new) - diff).abs() < 0.01, "{}", new);
+        }
+
+        assert_eq!(profile.gain(100.0), 0.0);
+    }
+
+    #[test]
+    fn raw_pp_needed_is_inverse() {
+        let profile = ProfilePp::from_pps(vec![300.0, 250.0, 240.0, 100.0]);
+
+        for &gain in &[1.0, 10.0, 100.0] {
+            let raw = profile.raw_pp_needed(gain);
+            assert!((profile.gain(raw) - gain).abs() < 0.01, "{}", gain);
+        }
+
+        assert_eq!(profile.raw_pp_needed(0.0), 0.0);
+    }
+}
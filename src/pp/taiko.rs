@@ -0,0 +1,95 @@
+use crate::model::{score::ScoreStatistics, GameMode, GameMods};
+
+pub(super) fn performance(
+    stars: f64,
+    great_hit_window: f64,
+    mods: GameMods,
+    statistics: &ScoreStatistics,
+) -> f64 {
+    let total_hits = statistics.total_hits(GameMode::Taiko) as f64;
+
+    if total_hits <= 0.0 {
+        return 0.0;
+    }
+
+    let acc = (statistics.count_300 as f64 + 0.5 * statistics.count_100 as f64) / total_hits;
+    let successful_hits = (statistics.count_300 + statistics.count_100) as f64;
+
+    let effective_misses = if successful_hits > 0.0 {
+        (1000.0 / successful_hits).max(1.0) * statistics.count_miss as f64
+    } else {
+        statistics.count_miss as f64
+    };
+
+    let mut multiplier = 1.13;
+
+    if mods.contains(GameMods::Hidden) {
+        multiplier *= 1.075;
+    }
+
+    if mods.contains(GameMods::Easy) {
+        multiplier *= 0.975;
+    }
+
+    let difficulty = difficulty_value(stars, mods, total_hits, acc, effective_misses);
+    let accuracy = accuracy_value(stars, great_hit_window, mods, total_hits, acc);
+
+    (difficulty.powf(1.1) + accuracy.powf(1.1)).powf(1.0 / 1.1) * multiplier
+}
+
+fn difficulty_value(
+    stars: f64,
+    mods: GameMods,
+    total_hits: f64,
+    acc: f64,
+    effective_misses: f64,
+) -> f64 {
+    let exp_base = 5.0 * (stars / 0.115).max(1.0) - 4.0;
+    let mut difficulty = exp_base.powf(2.25) / 1150.0;
+
+    let len_bonus = 1.0 + 0.1 * (total_hits / 1500.0).min(1.0);
+    difficulty *= len_bonus;
+
+    difficulty *= 0.986_f64.powf(effective_misses);
+
+    if mods.contains(GameMods::Easy) {
+        difficulty *= 0.985;
+    }
+
+    if mods.contains(GameMods::Hidden) {
+        difficulty *= 1.025;
+    }
+
+    if mods.contains(GameMods::HardRock) {
+        difficulty *= 1.05;
+    }
+
+    if mods.contains(GameMods::Flashlight) {
+        difficulty *= 1.05 * len_bonus;
+    }
+
+    difficulty * acc * acc
+}
+
+fn accuracy_value(
+    stars: f64,
+    great_hit_window: f64,
+    mods: GameMods,
+    total_hits: f64,
+    acc: f64,
+) -> f64 {
+    if great_hit_window <= 0.0 {
+        return 0.0;
+    }
+
+    let mut accuracy = (60.0 / great_hit_window).powf(1.1) * acc.powi(8) * stars.powf(0.4) * 27.0;
+
+    let len_bonus = (total_hits / 1500.0).powf(0.3).min(1.15);
+    accuracy *= len_bonus;
+
+    if mods.contains(GameMods::Hidden | GameMods::Flashlight) {
+        accuracy *= (1.075 * len_bonus).max(1.05);
+    }
+
+    accuracy
+}
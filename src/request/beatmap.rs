@@ -6,10 +6,10 @@ use crate::{
         },
         beatmap_::{
             BeatmapDifficultyAttributes, BeatmapDifficultyAttributesWrapper, Beatmaps,
-            SearchRankStatus,
+            BeatmapsetFavouriteCount, SearchRankStatus,
         },
         score_::{BeatmapScores, BeatmapUserScore, Score, Scores},
-        Cursor, GameMode, GameMods,
+        Cursor, GameMode, GameMods, LazerMod, LazerMods, ModSetting, ModSettingValue,
     },
     prelude::BeatmapCompact,
     request::{Pending, Query, Request},
@@ -18,6 +18,7 @@ use crate::{
 };
 
 use futures::future::TryFutureExt;
+use serde_json::{Number, Value};
 use std::{
     fmt::{Display, Formatter, Result as FmtResult, Write},
     mem,
@@ -152,7 +153,7 @@ pub struct GetBeatmapDifficultyAttributes<'a> {
     osu: &'a Osu,
     map_id: u32,
     mode: Option<GameMode>,
-    mods: Option<GameMods>,
+    mods: Option<LazerMods>,
 }
 
 impl<'a> GetBeatmapDifficultyAttributes<'a> {
@@ -174,10 +175,11 @@ impl<'a> GetBeatmapDifficultyAttributes<'a> {
         self
     }
 
-    /// Specify the mods
+    /// Specify the mods, either as [`GameMods`] or as [`LazerMods`]
+    /// which may include osu!lazer mods and mod settings.
     #[inline]
-    pub fn mods(mut self, mods: GameMods) -> Self {
-        self.mods = Some(mods);
+    pub fn mods(mut self, mods: impl Into<LazerMods>) -> Self {
+        self.mods = Some(mods.into());
 
         self
     }
@@ -192,8 +194,11 @@ impl<'a> GetBeatmapDifficultyAttributes<'a> {
 
         let mut body = Body::default();
 
-        if let Some(mods) = self.mods {
-            body.push_without_quotes("mods", mods.bits());
+        if let Some(ref mods) = self.mods {
+            match mods.try_legacy() {
+                Some(mods) => body.push_without_quotes("mods", mods.bits()),
+                None => body.push_objects("mods", mods.iter().map(mod_body)),
+            }
         }
 
         if let Some(mode) = self.mode {
@@ -213,6 +218,35 @@ impl<'a> GetBeatmapDifficultyAttributes<'a> {
 
 poll_req!(GetBeatmapDifficultyAttributes => BeatmapDifficultyAttributes);
 
+fn mod_body(m: &LazerMod) -> Body {
+    let mut body = Body::default();
+    body.push_escaped("acronym", m.acronym());
+
+    let settings = m.settings();
+
+    if !settings.is_empty() {
+        let mut settings_body = Body::default();
+
+        for ModSetting { name, value } in settings {
+            let value = match value {
+                ModSettingValue::Bool(value) => Value::Bool(value),
+                // JSON can't represent NaN or infinity so such settings are skipped
+                ModSettingValue::Number(value) => match Number::from_f64(value) {
+                    Some(value) => Value::Number(value),
+                    None => continue,
+                },
+                ModSettingValue::String(value) => Value::String(value),
+            };
+
+            settings_body.push_value(&name, &value);
+        }
+
+        body.push_object("settings", settings_body);
+    }
+
+    body
+}
+
 #[derive(Copy, Clone, Debug)]
 enum ScoreType {
     Country,
@@ -315,12 +349,12 @@ impl<'a> GetBeatmapScores<'a> {
         let mut query = Query::new();
 
         if let Some(mode) = self.mode {
-            query.push("mode", &mode.to_string());
+            query.push("mode", mode.to_string());
         }
 
         if let Some(mods) = self.mods {
             for m in mods {
-                query.push("mods[]", &m.to_string());
+                query.push("mods[]", m.to_string());
             }
         }
 
@@ -429,12 +463,12 @@ impl<'a> GetBeatmapUserScore<'a> {
         let mut query = Query::new();
 
         if let Some(mode) = self.mode {
-            query.push("mode", &mode.to_string());
+            query.push("mode", mode.to_string());
         }
 
         if let Some(mods) = self.mods {
             for m in mods {
-                query.push("mods[]", &m.to_string());
+                query.push("mods[]", m.to_string());
             }
         }
 
@@ -533,7 +567,7 @@ impl<'a> GetBeatmapUserScores<'a> {
         let mut query = Query::new();
 
         if let Some(mode) = self.mode {
-            query.push("mode", &mode.to_string());
+            query.push("mode", mode.to_string());
         }
 
         let osu = self.osu;
@@ -614,6 +648,57 @@ impl<'a> GetBeatmapset<'a> {
 
 poll_req!(GetBeatmapset => Beatmapset);
 
+/// Add a [`Beatmapset`](crate::model::beatmap::Beatmapset) to the authenticated
+/// user's favourites or remove it and receive the mapset's updated favourite count.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct FavouriteBeatmapset<'a> {
+    fut: Option<Pending<'a, u32>>,
+    osu: &'a Osu,
+    mapset_id: u32,
+    favourite: bool,
+}
+
+impl<'a> FavouriteBeatmapset<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mapset_id: u32, favourite: bool) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mapset_id,
+            favourite,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, u32> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.beatmapset_favourite.inc();
+
+        let action = if self.favourite {
+            "favourite"
+        } else {
+            "unfavourite"
+        };
+
+        let mut body = Body::default();
+        body.push_with_quotes("action", action);
+
+        let route = Route::PostBeatmapsetFavourite {
+            mapset_id: self.mapset_id,
+        };
+
+        let req = Request::with_body(route, body);
+
+        let fut = self
+            .osu
+            .request::<BeatmapsetFavouriteCount>(req)
+            .map_ok(|count| count.favourite_count);
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(FavouriteBeatmapset => u32);
+
 /// Get a [`Beatmapset`](crate::model::beatmap::Beatmapset) from a beatmap ID.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmapsetFromMapId<'a> {
@@ -944,21 +1029,33 @@ poll_req!(GetBeatmapsetSearch => BeatmapsetSearchResult);
 pub struct GetScore<'a> {
     fut: Option<Pending<'a, Score>>,
     osu: &'a Osu,
-    mode: GameMode,
+    mode: Option<GameMode>,
     score_id: u64,
 }
 
 impl<'a> GetScore<'a> {
     #[inline]
-    pub(crate) fn new(osu: &'a Osu, score_id: u64, mode: GameMode) -> Self {
+    pub(crate) fn new(osu: &'a Osu, score_id: u64) -> Self {
         Self {
             fut: None,
             osu,
-            mode,
+            mode: None,
             score_id,
         }
     }
 
+    /// Specify the mode of the score in case the score id is
+    /// a legacy score id of osu!stable for that mode.
+    ///
+    /// Without a specified mode, the score id is considered to be
+    /// an osu!lazer score id.
+    #[inline]
+    pub fn mode(mut self, mode: GameMode) -> Self {
+        self.mode.replace(mode);
+
+        self
+    }
+
     fn start(&mut self) -> Pending<'a, Score> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.score.inc();
@@ -968,8 +1065,10 @@ impl<'a> GetScore<'a> {
             score_id: self.score_id,
         };
 
+        let req = Request::new(route);
+
         let osu = self.osu;
-        let fut = osu.request::<Score>(Request::new(route));
+        let fut = osu.request::<Score>(req);
 
         #[cfg(feature = "cache")]
         let fut = fut.inspect_ok(move |score| {
@@ -983,3 +1082,41 @@ impl<'a> GetScore<'a> {
 }
 
 poll_req!(GetScore => Score);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mod_body_is_valid_json() {
+        let setting = |name: &str, value| ModSetting {
+            name: name.to_owned(),
+            value,
+        };
+
+        let m = LazerMod::Unknown {
+            acronym: "X\"Y".to_owned(),
+            settings: vec![
+                setting("quote\"d", ModSettingValue::String("a\\b".to_owned())),
+                setting("nan", ModSettingValue::Number(f64::NAN)),
+                setting("inf", ModSettingValue::Number(f64::INFINITY)),
+                setting("rate", ModSettingValue::Number(1.5)),
+                setting("flag", ModSettingValue::Bool(true)),
+            ],
+        };
+
+        let bytes = mod_body(&m).into_bytes();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+
+        let expected = serde_json::json!({
+            "acronym": "X\"Y",
+            "settings": {
+                "quote\"d": "a\\b",
+                "rate": 1.5,
+                "flag": true,
+            },
+        });
+
+        assert_eq!(value, expected);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    model::changelog_::{Changelog, ChangelogBuild},
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+/// Get a [`Changelog`](crate::model::changelog::Changelog) struct containing
+/// a list of builds and all update streams.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetChangelog<'a> {
+    fut: Option<Pending<'a, Changelog>>,
+    osu: &'a Osu,
+    stream: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    max_id: Option<u32>,
+}
+
+impl<'a> GetChangelog<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            stream: None,
+            from: None,
+            to: None,
+            max_id: None,
+        }
+    }
+
+    /// Only include builds of the given update stream e.g. `stable40`, `lazer`, or `web`
+    #[inline]
+    pub fn stream(mut self, stream: impl Into<String>) -> Self {
+        self.stream.replace(stream.into());
+
+        self
+    }
+
+    /// Minimum build version
+    #[inline]
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from.replace(from.into());
+
+        self
+    }
+
+    /// Maximum build version
+    #[inline]
+    pub fn to(mut self, to: impl Into<String>) -> Self {
+        self.to.replace(to.into());
+
+        self
+    }
+
+    /// Maximum build id
+    #[inline]
+    pub fn max_id(mut self, max_id: u32) -> Self {
+        self.max_id.replace(max_id);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Changelog> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.changelog.inc();
+
+        let mut query = Query::new();
+
+        if let Some(stream) = self.stream.take() {
+            query.push("stream", stream);
+        }
+
+        if let Some(from) = self.from.take() {
+            query.push("from", from);
+        }
+
+        if let Some(to) = self.to.take() {
+            query.push("to", to);
+        }
+
+        if let Some(max_id) = self.max_id {
+            query.push("max_id", max_id);
+        }
+
+        query.push("message_formats[]", "markdown");
+        query.push("message_formats[]", "html");
+
+        let req = Request::with_query(Route::GetChangelog, query);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetChangelog => Changelog);
+
+/// Get a specific [`ChangelogBuild`](crate::model::changelog::ChangelogBuild)
+/// including its changelog entries and neighbouring versions.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetChangelogBuild<'a> {
+    fut: Option<Pending<'a, ChangelogBuild>>,
+    osu: &'a Osu,
+    stream: Option<String>,
+    version: Option<String>,
+}
+
+impl<'a> GetChangelogBuild<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, stream: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            fut: None,
+            osu,
+            stream: Some(stream.into()),
+            version: Some(version.into()),
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, ChangelogBuild> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.changelog_build.inc();
+
+        let route = Route::GetChangelogBuild {
+            stream: self.stream.take().unwrap(),
+            version: self.version.take().unwrap(),
+        };
+
+        let req = Request::new(route);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetChangelogBuild => ChangelogBuild);
@@ -1,9 +1,9 @@
 use crate::{
     model::{
-        comments_::{CommentBundle, CommentSort},
+        comments_::{CommentBundle, CommentSort, CommentableType},
         Cursor,
     },
-    request::{Pending, Query, Request},
+    request::{Body, Pending, Query, Request},
     routing::Route,
     Osu,
 };
@@ -14,7 +14,7 @@ use crate::{
 pub struct GetComments<'a> {
     fut: Option<Pending<'a, CommentBundle>>,
     osu: &'a Osu,
-    commentable_type: Option<String>,
+    commentable_type: Option<CommentableType>,
     commentable_id: Option<u32>,
     parent_id: Option<u32>,
     sort: Option<CommentSort>,
@@ -77,8 +77,8 @@ impl<'a> GetComments<'a> {
 
     /// The type of resource to get comments for
     #[inline]
-    pub fn commentable_type(mut self, commentable_type: impl Into<String>) -> Self {
-        self.commentable_type.replace(commentable_type.into());
+    pub fn commentable_type(mut self, commentable_type: CommentableType) -> Self {
+        self.commentable_type.replace(commentable_type);
 
         self
     }
@@ -97,7 +97,7 @@ impl<'a> GetComments<'a> {
         let mut query = Query::new();
 
         if let Some(sort) = self.sort {
-            query.push("sort", &sort.to_string());
+            query.push("sort", sort.to_string());
         }
 
         if let Some(parent) = self.parent_id {
@@ -108,8 +108,8 @@ impl<'a> GetComments<'a> {
             query.push("commentable_id", commentable);
         }
 
-        if let Some(commentable) = self.commentable_type.take() {
-            query.push("commentable_type", &commentable);
+        if let Some(commentable) = self.commentable_type {
+            query.push("commentable_type", commentable);
         }
 
         if let Some(cursor) = self.cursor.take() {
@@ -123,3 +123,184 @@ impl<'a> GetComments<'a> {
 }
 
 poll_req!(GetComments => CommentBundle);
+
+/// Post a new comment or a reply to a comment and receive the resulting
+/// [`CommentBundle`](crate::model::comments::CommentBundle).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct PostComment<'a> {
+    fut: Option<Pending<'a, CommentBundle>>,
+    osu: &'a Osu,
+    commentable_type: CommentableType,
+    commentable_id: u32,
+    message: String,
+    parent_id: Option<u32>,
+}
+
+impl<'a> PostComment<'a> {
+    #[inline]
+    pub(crate) fn new(
+        osu: &'a Osu,
+        commentable_type: CommentableType,
+        commentable_id: u32,
+        message: String,
+    ) -> Self {
+        Self {
+            fut: None,
+            osu,
+            commentable_type,
+            commentable_id,
+            message,
+            parent_id: None,
+        }
+    }
+
+    /// Post the comment as reply to the comment with the given id
+    #[inline]
+    pub fn parent(mut self, parent_id: u32) -> Self {
+        self.parent_id.replace(parent_id);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, CommentBundle> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.post_comment.inc();
+
+        let mut comment = Body::default();
+        comment.push_with_quotes("commentable_type", self.commentable_type);
+        comment.push_without_quotes("commentable_id", self.commentable_id);
+        comment.push_escaped("message", &self.message);
+
+        if let Some(parent) = self.parent_id {
+            comment.push_without_quotes("parent_id", parent);
+        }
+
+        let mut body = Body::default();
+        body.push_object("comment", comment);
+
+        let req = Request::with_body(Route::PostComment, body);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(PostComment => CommentBundle);
+
+/// Edit the message of one of your own comments and receive the resulting
+/// [`CommentBundle`](crate::model::comments::CommentBundle).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct EditComment<'a> {
+    fut: Option<Pending<'a, CommentBundle>>,
+    osu: &'a Osu,
+    comment_id: u32,
+    message: String,
+}
+
+impl<'a> EditComment<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, comment_id: u32, message: String) -> Self {
+        Self {
+            fut: None,
+            osu,
+            comment_id,
+            message,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, CommentBundle> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.edit_comment.inc();
+
+        let mut comment = Body::default();
+        comment.push_escaped("message", &self.message);
+
+        let mut body = Body::default();
+        body.push_object("comment", comment);
+
+        let route = Route::PutComment {
+            comment_id: self.comment_id,
+        };
+
+        let req = Request::with_body(route, body);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(EditComment => CommentBundle);
+
+/// Delete one of your own comments and receive the resulting
+/// [`CommentBundle`](crate::model::comments::CommentBundle).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DeleteComment<'a> {
+    fut: Option<Pending<'a, CommentBundle>>,
+    osu: &'a Osu,
+    comment_id: u32,
+}
+
+impl<'a> DeleteComment<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, comment_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            comment_id,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, CommentBundle> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.delete_comment.inc();
+
+        let route = Route::DeleteComment {
+            comment_id: self.comment_id,
+        };
+
+        let req = Request::new(route);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(DeleteComment => CommentBundle);
+
+/// Upvote a comment or remove your upvote and receive the resulting
+/// [`CommentBundle`](crate::model::comments::CommentBundle).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct VoteComment<'a> {
+    fut: Option<Pending<'a, CommentBundle>>,
+    osu: &'a Osu,
+    comment_id: u32,
+    upvote: bool,
+}
+
+impl<'a> VoteComment<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, comment_id: u32, upvote: bool) -> Self {
+        Self {
+            fut: None,
+            osu,
+            comment_id,
+            upvote,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, CommentBundle> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.vote_comment.inc();
+
+        let comment_id = self.comment_id;
+
+        let route = if self.upvote {
+            Route::PostCommentVote { comment_id }
+        } else {
+            Route::DeleteCommentVote { comment_id }
+        };
+
+        let req = Request::new(route);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(VoteComment => CommentBundle);
@@ -0,0 +1,252 @@
+use crate::{
+    model::recent_event_::{Events, RecentEvent},
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu, OsuResult,
+};
+
+use futures::{future::TryFutureExt, stream, Stream};
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+/// Get a page of the global events feed in form of [`Events`](crate::model::recent_event::Events).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetEvents<'a> {
+    fut: Option<Pending<'a, Events>>,
+    osu: &'a Osu,
+    ascending: bool,
+    cursor: Option<String>,
+}
+
+impl<'a> GetEvents<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            ascending: false,
+            cursor: None,
+        }
+    }
+
+    /// Sort by ascending event ids
+    #[inline]
+    pub fn sort_ascending(mut self) -> Self {
+        self.ascending = true;
+
+        self
+    }
+
+    /// Sort by descending event ids i.e. newest first. This is the default.
+    #[inline]
+    pub fn sort_descending(mut self) -> Self {
+        self.ascending = false;
+
+        self
+    }
+
+    /// Specify a page by providing a cursor
+    #[inline]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor.replace(cursor.into());
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Events> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.events.inc();
+
+        let ascending = self.ascending;
+        let mut query = Query::new();
+
+        query.push("sort", if ascending { "id_asc" } else { "id_desc" });
+
+        if let Some(cursor) = self.cursor.take() {
+            query.push("cursor_string", cursor);
+        }
+
+        let req = Request::with_query(Route::GetEvents, query);
+
+        let fut = self.osu.request::<Events>(req).map_ok(move |mut events| {
+            events.ascending = ascending;
+
+            events
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetEvents => Events);
+
+type PendingStream<'a> = Pin<Box<dyn Stream<Item = OsuResult<RecentEvent>> + Send + 'a>>;
+
+/// A [`Stream`] that repeatedly polls the global events feed and yields each
+/// [`RecentEvent`](crate::model::recent_event::RecentEvent) only once,
+/// oldest first.
+///
+/// Unless [`after`](EventStream::after) is specified, the first poll only
+/// marks the current position within the feed so that only new events are yielded.
+///
+/// If more events occured between two polls than fit on a single page,
+/// e.g. after a downtime, the cursor is followed until all unseen events
+/// are retrieved or [`max_pages`](EventStream::max_pages) is reached.
+///
+/// Errors are yielded but don't end the stream; the next poll will
+/// resume where the last successful one left off.
+#[must_use = "streams do nothing unless polled"]
+pub struct EventStream<'a> {
+    inner: Option<PendingStream<'a>>,
+    osu: &'a Osu,
+    interval: Duration,
+    after: Option<u32>,
+    max_pages: usize,
+}
+
+impl<'a> EventStream<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            inner: None,
+            osu,
+            interval: Duration::from_secs(60),
+            after: None,
+            max_pages: 10,
+        }
+    }
+
+    /// Duration to wait between two polls, defaults to 60 seconds.
+    #[inline]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Only yield events whose id is greater than the given one,
+    /// e.g. to resume from a previously seen event.
+    #[inline]
+    pub fn after(mut self, event_id: u32) -> Self {
+        self.after.replace(event_id);
+
+        self
+    }
+
+    /// Maximum amount of pages to request per poll when catching up, defaults to 10.
+    /// The given value will be at least 1.
+    #[inline]
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages.max(1);
+
+        self
+    }
+
+    fn start(&mut self) -> PendingStream<'a> {
+        let state = EventStreamState {
+            osu: self.osu,
+            interval: self.interval,
+            last_id: self.after,
+            max_pages: self.max_pages,
+            buffer: VecDeque::new(),
+            polled: false,
+        };
+
+        let stream = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(event) = state.buffer.pop_front() {
+                    return Some((Ok(event), state));
+                }
+
+                if state.polled {
+                    tokio::time::sleep(state.interval).await;
+                }
+
+                state.polled = true;
+
+                if let Err(err) = state.fetch_unseen().await {
+                    return Some((Err(err), state));
+                }
+            }
+        });
+
+        Box::pin(stream)
+    }
+}
+
+impl Stream for EventStream<'_> {
+    type Item = OsuResult<RecentEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.inner {
+            Some(ref mut inner) => inner.as_mut().poll_next(cx),
+            None => {
+                let inner = self.start();
+
+                self.inner.get_or_insert(inner).as_mut().poll_next(cx)
+            }
+        }
+    }
+}
+
+struct EventStreamState<'a> {
+    osu: &'a Osu,
+    interval: Duration,
+    last_id: Option<u32>,
+    max_pages: usize,
+    buffer: VecDeque<RecentEvent>,
+    polled: bool,
+}
+
+impl EventStreamState<'_> {
+    /// Request the newest events and follow the cursor until
+    /// reaching the last seen event, then buffer them oldest first.
+    async fn fetch_unseen(&mut self) -> OsuResult<()> {
+        let mut unseen = Vec::new();
+        let mut cursor = None;
+
+        for _ in 0..self.max_pages {
+            let mut fut = self.osu.events().sort_descending();
+
+            if let Some(cursor) = cursor.take() {
+                fut = fut.cursor(cursor);
+            }
+
+            let page = fut.await?;
+
+            let last_id = match self.last_id {
+                Some(last_id) => last_id,
+                None => {
+                    self.last_id = page.events.first().map(|event| event.event_id);
+
+                    return Ok(());
+                }
+            };
+
+            let reached_seen = page.events.iter().any(|event| event.event_id <= last_id);
+
+            unseen.extend(
+                page.events
+                    .into_iter()
+                    .take_while(|event| event.event_id > last_id),
+            );
+
+            match page.cursor {
+                Some(next) if !reached_seen => cursor = Some(next),
+                _ => break,
+            }
+        }
+
+        if let Some(newest) = unseen.first() {
+            self.last_id = Some(newest.event_id);
+        }
+
+        self.buffer.extend(unseen.into_iter().rev());
+
+        Ok(())
+    }
+}
@@ -1,15 +1,23 @@
 use crate::{
     model::{
-        matches_::{MatchList, OsuMatch},
+        matches_::{MatchEvent, MatchGame, MatchList, MatchUpdate, OsuMatch},
         Cursor,
     },
     request::{Pending, Query, Request},
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
 #[cfg(feature = "cache")]
 use futures::TryFutureExt;
+use futures::{stream, Stream};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 /// Get an [`OsuMatch`](crate::model::matches::OsuMatch) by its id
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -102,6 +110,87 @@ impl<'a> GetMatch<'a> {
 
 poll_req!(GetMatch => OsuMatch);
 
+/// Get an [`OsuMatch`](crate::model::matches::OsuMatch) including all of its events
+/// by paging backwards until the first event.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetMatchFull<'a> {
+    fut: Option<Pending<'a, OsuMatch>>,
+    osu: &'a Osu,
+    match_id: u32,
+    max_pages: Option<usize>,
+}
+
+impl<'a> GetMatchFull<'a> {
+    /// The API sends at most 100 events per request
+    const PAGE_SIZE: usize = 100;
+
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, match_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            match_id,
+            max_pages: None,
+        }
+    }
+
+    /// Request at most `max_pages` many pages, including the initial one.
+    ///
+    /// If the limit is reached before the first event, the match will
+    /// only contain the most recent events, see [`OsuMatch::has_previous`].
+    ///
+    /// By default, there is no limit.
+    #[inline]
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages.replace(max_pages.max(1));
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, OsuMatch> {
+        let osu = self.osu;
+        let match_id = self.match_id;
+        let max_pages = self.max_pages.unwrap_or(usize::MAX);
+
+        let fut = async move {
+            let mut osu_match = GetMatch::new(osu, match_id).await?;
+            let mut pages = 1;
+
+            while pages < max_pages {
+                let first_id = match osu_match.events.first() {
+                    Some(event) if osu_match.has_previous() => event.event_id(),
+                    _ => break,
+                };
+
+                let previous = GetMatch::new(osu, match_id)
+                    .before(first_id)
+                    .limit(Self::PAGE_SIZE)
+                    .await?;
+
+                pages += 1;
+
+                // Prevent looping forever if the API does not make progress
+                let progressed = previous
+                    .events
+                    .first()
+                    .is_some_and(|event| event.event_id() < first_id);
+
+                osu_match.merge(previous);
+
+                if !progressed {
+                    break;
+                }
+            }
+
+            Ok(osu_match)
+        };
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetMatchFull => OsuMatch);
+
 /// Get a [`MatchList`](crate::model::matches::MatchList) containing all
 /// currently open multiplayer lobbies.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -145,3 +234,444 @@ impl<'a> GetMatches<'a> {
 }
 
 poll_req!(GetMatches => MatchList);
+
+type PendingStream<'a> = Pin<Box<dyn Stream<Item = OsuResult<MatchUpdate>> + Send + 'a>>;
+
+/// A [`Stream`] that repeatedly polls a multiplayer match and yields its
+/// [`MatchUpdate`](crate::model::matches::MatchUpdate)s.
+///
+/// Each new [`MatchEvent`](crate::model::matches::MatchEvent) is yielded once.
+/// Games that are still in progress are additionally yielded whenever they receive
+/// new data until their scores are final.
+///
+/// Unless [`after`](MatchStream::after) is specified, the first poll only marks the
+/// current position within the match and yields the game that is currently in progress, if any.
+///
+/// While the lobby is idle, the duration between polls is doubled up to
+/// [`max_interval`](MatchStream::max_interval) and reset as soon as something happens.
+///
+/// Errors are yielded but don't end the stream; the next poll will resume where the
+/// last successful one left off. Polls after an error are backed off the same way
+/// as while idle. The stream ends after yielding the
+/// [`MatchEvent::Disbanded`](crate::model::matches::MatchEvent::Disbanded) event.
+#[must_use = "streams do nothing unless polled"]
+pub struct MatchStream<'a> {
+    inner: Option<PendingStream<'a>>,
+    osu: &'a Osu,
+    match_id: u32,
+    interval: Duration,
+    max_interval: Duration,
+    after: Option<u64>,
+}
+
+impl<'a> MatchStream<'a> {
+    /// The API sends at most 100 events per request
+    const PAGE_SIZE: usize = 100;
+
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, match_id: u32) -> Self {
+        Self {
+            inner: None,
+            osu,
+            match_id,
+            interval: Duration::from_secs(10),
+            max_interval: Duration::from_secs(120),
+            after: None,
+        }
+    }
+
+    /// Duration to wait between two polls while the lobby is active, defaults to 10 seconds.
+    #[inline]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Maximum duration to wait between two polls while the lobby is idle, defaults to 120 seconds.
+    #[inline]
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+
+        self
+    }
+
+    /// Only yield events whose id is greater than the given one,
+    /// e.g. to resume from a previously seen event.
+    #[inline]
+    pub fn after(mut self, event_id: u64) -> Self {
+        self.after.replace(event_id);
+
+        self
+    }
+
+    fn start(&mut self) -> PendingStream<'a> {
+        let state = MatchStreamState {
+            interval: self.interval,
+            max_interval: self.max_interval.max(self.interval),
+            current_interval: self.interval,
+            last_id: self.after,
+            in_progress: None,
+            buffer: VecDeque::new(),
+            polled: false,
+            pending_events: false,
+            disbanded: false,
+        };
+
+        let osu = self.osu;
+        let match_id = self.match_id;
+
+        let fetch = move |after: Option<u64>| {
+            let req = GetMatch::new(osu, match_id).limit(Self::PAGE_SIZE);
+
+            match after {
+                Some(after) => req.after(after),
+                None => req,
+            }
+        };
+
+        let stream = stream::unfold(state, move |mut state| async move {
+            let update = state.next_update(fetch).await?;
+
+            Some((update, state))
+        });
+
+        Box::pin(stream)
+    }
+}
+
+impl Stream for MatchStream<'_> {
+    type Item = OsuResult<MatchUpdate>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.inner {
+            Some(ref mut inner) => inner.as_mut().poll_next(cx),
+            None => {
+                let inner = self.start();
+
+                self.inner.get_or_insert(inner).as_mut().poll_next(cx)
+            }
+        }
+    }
+}
+
+struct MatchStreamState {
+    interval: Duration,
+    max_interval: Duration,
+    current_interval: Duration,
+    last_id: Option<u64>,
+    /// Event id and last yielded state of the game in progress
+    in_progress: Option<(u64, Box<MatchGame>)>,
+    buffer: VecDeque<MatchUpdate>,
+    polled: bool,
+    /// Whether the last page was full i.e. more events can be requested immediately
+    pending_events: bool,
+    disbanded: bool,
+}
+
+impl MatchStreamState {
+    /// Yield the next buffered update or wait and poll for new ones through `fetch`
+    /// which requests the page of events after the given event id.
+    async fn next_update<F, Fut>(&mut self, mut fetch: F) -> Option<OsuResult<MatchUpdate>>
+    where
+        F: FnMut(Option<u64>) -> Fut,
+        Fut: Future<Output = OsuResult<OsuMatch>>,
+    {
+        loop {
+            if let Some(update) = self.buffer.pop_front() {
+                return Some(Ok(update));
+            }
+
+            if self.disbanded {
+                return None;
+            }
+
+            if self.polled && !self.pending_events {
+                tokio::time::sleep(self.current_interval).await;
+            }
+
+            self.polled = true;
+
+            match fetch(self.after()).await {
+                Ok(osu_match) => {
+                    if self.process(osu_match) || self.in_progress.is_some() {
+                        self.current_interval = self.interval;
+                    } else {
+                        self.back_off();
+                    }
+                }
+                Err(err) => {
+                    // Wait before retrying, regardless of the previous page
+                    self.pending_events = false;
+                    self.back_off();
+
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+
+    fn back_off(&mut self) {
+        self.current_interval = (self.current_interval * 2).min(self.max_interval);
+    }
+
+    /// The event id after which the next page starts, i.e. the last seen one,
+    /// or right before the game in progress.
+    fn after(&self) -> Option<u64> {
+        match self.in_progress {
+            Some((event_id, _)) => Some(event_id - 1),
+            None => self.last_id,
+        }
+    }
+
+    /// Buffer all updates of the requested page.
+    ///
+    /// Returns whether there were any updates.
+    fn process(&mut self, osu_match: OsuMatch) -> bool {
+        self.pending_events = osu_match.events.len() >= MatchStream::PAGE_SIZE;
+
+        let last_id = match self.last_id {
+            Some(last_id) => last_id,
+            None => {
+                self.mark_position(osu_match);
+
+                return true;
+            }
+        };
+
+        let prev_len = self.buffer.len();
+        let mut last_id = last_id;
+
+        for event in osu_match.events {
+            let event_id = event.event_id();
+
+            if let Some((in_progress_id, ref mut prev)) = self.in_progress {
+                if event_id == in_progress_id {
+                    if let MatchEvent::Game { game, .. } = event {
+                        let ended = game.end_time.is_some();
+
+                        if game != *prev {
+                            *prev = game.clone();
+                            self.buffer.push_back(MatchUpdate::Game { event_id, game });
+                        }
+
+                        if ended {
+                            self.in_progress = None;
+                        }
+                    }
+
+                    continue;
+                }
+            }
+
+            if event_id <= last_id {
+                continue;
+            }
+
+            last_id = event_id;
+
+            match event {
+                MatchEvent::Game { ref game, .. } if game.end_time.is_none() => {
+                    // Only one game can be in progress at a time
+                    self.in_progress = Some((event_id, game.clone()));
+                }
+                MatchEvent::Disbanded { .. } => self.disbanded = true,
+                _ => {}
+            }
+
+            self.buffer.push_back(MatchUpdate::Event(event));
+        }
+
+        self.last_id = Some(last_id);
+
+        self.buffer.len() > prev_len
+    }
+
+    /// Remember the latest event and buffer the game in progress, if any.
+    fn mark_position(&mut self, osu_match: OsuMatch) {
+        self.last_id = Some(osu_match.latest_event_id);
+        self.pending_events = false;
+
+        let last_game = osu_match.events.into_iter().rev().find(|event| {
+            matches!(
+                event,
+                MatchEvent::Game { .. } | MatchEvent::Disbanded { .. }
+            )
+        });
+
+        match last_game {
+            Some(MatchEvent::Game {
+                event_id,
+                game,
+                match_name,
+                timestamp,
+            }) if game.end_time.is_none() => {
+                self.in_progress = Some((event_id, game.clone()));
+
+                self.buffer.push_back(MatchUpdate::Event(MatchEvent::Game {
+                    event_id,
+                    game,
+                    match_name,
+                    timestamp,
+                }));
+            }
+            Some(event @ MatchEvent::Disbanded { .. }) => {
+                self.disbanded = true;
+                self.buffer.push_back(MatchUpdate::Event(event));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::OsuError;
+
+    use futures::future::{ready, Ready};
+    use std::{cell::RefCell, collections::HashMap};
+    use time::OffsetDateTime;
+
+    fn state(after: Option<u64>) -> MatchStreamState {
+        MatchStreamState {
+            interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(4),
+            current_interval: Duration::from_millis(1),
+            last_id: after,
+            in_progress: None,
+            buffer: VecDeque::new(),
+            polled: false,
+            pending_events: false,
+            disbanded: false,
+        }
+    }
+
+    fn joined(event_id: u64) -> MatchEvent {
+        MatchEvent::Joined {
+            event_id,
+            timestamp: OffsetDateTime::UNIX_EPOCH,
+            user_id: 2,
+        }
+    }
+
+    fn osu_match(events: Vec<MatchEvent>) -> OsuMatch {
+        OsuMatch {
+            current_game_id: None,
+            end_time: None,
+            first_event_id: events.first().map_or(0, MatchEvent::event_id),
+            latest_event_id: events.last().map_or(0, MatchEvent::event_id),
+            events,
+            match_id: 1,
+            name: "match".to_owned(),
+            start_time: OffsetDateTime::UNIX_EPOCH,
+            users: HashMap::new(),
+        }
+    }
+
+    /// Yields the given pages in order and records the requested positions
+    struct FakePages {
+        pages: RefCell<VecDeque<OsuResult<OsuMatch>>>,
+        requested: RefCell<Vec<Option<u64>>>,
+    }
+
+    impl FakePages {
+        fn new(pages: Vec<OsuResult<OsuMatch>>) -> Self {
+            Self {
+                pages: RefCell::new(pages.into()),
+                requested: RefCell::new(Vec::new()),
+            }
+        }
+
+        fn fetch(&self, after: Option<u64>) -> Ready<OsuResult<OsuMatch>> {
+            self.requested.borrow_mut().push(after);
+            let page = self.pages.borrow_mut().pop_front().expect("no more pages");
+
+            ready(page)
+        }
+    }
+
+    fn event_id(update: Option<OsuResult<MatchUpdate>>) -> u64 {
+        match update {
+            Some(Ok(MatchUpdate::Event(event))) => event.event_id(),
+            other => panic!("expected event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn first_poll_marks_position() {
+        let pages = FakePages::new(vec![
+            Ok(osu_match(vec![joined(1), joined(2)])),
+            Ok(osu_match(vec![joined(2), joined(3)])),
+        ]);
+
+        let mut state = state(None);
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        rt.block_on(async {
+            assert_eq!(
+                event_id(state.next_update(|after| pages.fetch(after)).await),
+                3
+            );
+        });
+
+        assert_eq!(*pages.requested.borrow(), [None, Some(2)]);
+    }
+
+    #[test]
+    fn backs_off_after_error() {
+        let full_page = (1..=MatchStream::PAGE_SIZE as u64).map(joined).collect();
+
+        let pages = FakePages::new(vec![
+            Ok(osu_match(full_page)),
+            Err(OsuError::NotFound),
+            Err(OsuError::NotFound),
+            Ok(osu_match(vec![MatchEvent::Disbanded {
+                event_id: 101,
+                timestamp: OffsetDateTime::UNIX_EPOCH,
+            }])),
+        ]);
+
+        let mut state = state(Some(0));
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        rt.block_on(async {
+            let mut fetch = |after| pages.fetch(after);
+
+            for expected in 1..=MatchStream::PAGE_SIZE as u64 {
+                assert_eq!(event_id(state.next_update(&mut fetch).await), expected);
+            }
+
+            assert!(state.pending_events);
+
+            // The full page would skip the delay but the error must not
+            assert!(matches!(
+                state.next_update(&mut fetch).await,
+                Some(Err(OsuError::NotFound))
+            ));
+            assert!(!state.pending_events);
+            assert_eq!(state.current_interval, Duration::from_millis(2));
+
+            assert!(matches!(
+                state.next_update(&mut fetch).await,
+                Some(Err(OsuError::NotFound))
+            ));
+            assert_eq!(state.current_interval, Duration::from_millis(4));
+
+            assert_eq!(event_id(state.next_update(&mut fetch).await), 101);
+            assert_eq!(state.current_interval, Duration::from_millis(1));
+            assert!(state.next_update(&mut fetch).await.is_none());
+        });
+
+        assert_eq!(
+            *pages.requested.borrow(),
+            [Some(0), Some(100), Some(100), Some(100)]
+        );
+    }
+}
@@ -21,23 +21,31 @@ macro_rules! poll_req {
 }
 
 mod beatmap;
+mod changelog;
 mod comments;
+mod event;
 mod forum;
 mod matches;
 mod news;
+mod notification;
 mod ranking;
 mod replay;
+mod search;
 mod seasonal_backgrounds;
 mod user;
 mod wiki;
 
 pub use beatmap::*;
+pub use changelog::*;
 pub use comments::*;
+pub use event::*;
 pub use forum::*;
 pub use matches::*;
 pub use news::*;
+pub use notification::*;
 pub use ranking::*;
 pub use replay::*;
+pub use search::*;
 pub use seasonal_backgrounds::*;
 pub use user::*;
 pub use wiki::*;
@@ -118,6 +126,49 @@ impl Body {
         let _ = write!(self.inner, "{value}");
     }
 
+    /// Push a string value while escaping it properly
+    pub(crate) fn push_escaped(&mut self, key: &str, value: &str) {
+        self.push_key(key);
+        let _ = write!(self.inner, "{}", serde_json::Value::from(value));
+    }
+
+    /// Push a JSON value while escaping the key properly
+    pub(crate) fn push_value(&mut self, key: &str, value: &serde_json::Value) {
+        self.push_prefix();
+        let _ = write!(self.inner, "{}:{}", serde_json::Value::from(key), value);
+    }
+
+    /// Push a nested JSON object
+    pub(crate) fn push_object(&mut self, key: &str, object: Body) {
+        self.push_key(key);
+        self.push_object_value(object);
+    }
+
+    /// Push an array of nested JSON objects
+    pub(crate) fn push_objects(&mut self, key: &str, objects: impl IntoIterator<Item = Body>) {
+        self.push_key(key);
+        self.inner.push('[');
+
+        for (i, object) in objects.into_iter().enumerate() {
+            if i > 0 {
+                self.inner.push(',');
+            }
+
+            self.push_object_value(object);
+        }
+
+        self.inner.push(']');
+    }
+
+    fn push_object_value(&mut self, object: Body) {
+        if object.inner.is_empty() {
+            self.inner.push_str("{}");
+        } else {
+            self.inner.push_str(&object.inner);
+            self.inner.push('}');
+        }
+    }
+
     pub(crate) fn into_bytes(mut self) -> Vec<u8> {
         if !self.inner.is_empty() {
             self.inner.push('}');
@@ -143,6 +194,15 @@ impl Query {
         let _ = write!(self.query, "{}", value);
         self.query.push('&');
     }
+
+    /// Push a string value while percent-encoding it properly
+    pub(crate) fn push_encoded(&mut self, key: &str, value: &str) {
+        self.query.push_str(key);
+        self.query.push('=');
+        self.query
+            .extend(url::form_urlencoded::byte_serialize(value.as_bytes()));
+        self.query.push('&');
+    }
 }
 
 impl Display for Query {
@@ -156,3 +216,17 @@ impl Display for Query {
         f.write_str(&self.query[..self.query.len() - 1])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_encodes_values() {
+        let mut query = Query::new();
+        query.push("mode", "all");
+        query.push_encoded("query", "a&b=c #d/é");
+
+        assert_eq!(query.to_string(), "?mode=all&query=a%26b%3Dc+%23d%2F%C3%A9");
+    }
+}
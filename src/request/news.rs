@@ -1,5 +1,8 @@
 use crate::{
-    model::{news_::News, Cursor},
+    model::{
+        news_::{News, NewsPost},
+        Cursor,
+    },
     request::{Pending, Query, Request},
     routing::Route,
     Osu,
@@ -10,7 +13,8 @@ use crate::{
 pub struct GetNews<'a> {
     fut: Option<Pending<'a, News>>,
     osu: &'a Osu,
-    news: Option<()>, // TODO
+    limit: Option<usize>,
+    year: Option<u32>,
     cursor: Option<Cursor>,
 }
 
@@ -20,21 +24,31 @@ impl<'a> GetNews<'a> {
         Self {
             fut: None,
             osu,
-            news: None,
+            limit: None,
+            year: None,
             cursor: None,
         }
     }
 
-    // TODO
-    // #[inline]
-    // pub fn news(mut self, news: ()) -> Self {
-    //     self.news.replace(news);
+    /// Maximum number of posts to be returned (12 default, 21 at most)
+    #[inline]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit.replace(limit.min(21));
 
-    //     self
-    // }
+        self
+    }
 
+    /// Only include posts of the given year
     #[inline]
-    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+    pub fn year(mut self, year: u32) -> Self {
+        self.year.replace(year);
+
+        self
+    }
+
+    /// Specify a page by providing a cursor
+    #[inline]
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
         self.cursor.replace(cursor);
 
         self
@@ -46,14 +60,75 @@ impl<'a> GetNews<'a> {
 
         let mut query = Query::new();
 
+        if let Some(limit) = self.limit {
+            query.push("limit", limit);
+        }
+
+        if let Some(year) = self.year {
+            query.push("year", year);
+        }
+
         if let Some(cursor) = self.cursor.take() {
             cursor.push_to_query(&mut query);
         }
 
-        let req = Request::with_query(Route::GetNews { news: self.news }, query);
+        let req = Request::with_query(Route::GetNews { news: None }, query);
 
         Box::pin(self.osu.request(req))
     }
 }
 
 poll_req!(GetNews => News);
+
+/// Get a single [`NewsPost`](crate::model::news::NewsPost)
+/// including its content and navigation.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetNewsPost<'a> {
+    fut: Option<Pending<'a, NewsPost>>,
+    osu: &'a Osu,
+    news: Option<String>,
+    key: Option<&'static str>,
+}
+
+impl<'a> GetNewsPost<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, slug: impl Into<String>) -> Self {
+        Self {
+            fut: None,
+            osu,
+            news: Some(slug.into()),
+            key: None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_id(osu: &'a Osu, post_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            news: Some(post_id.to_string()),
+            key: Some("id"),
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, NewsPost> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.news_post.inc();
+
+        let mut query = Query::new();
+
+        if let Some(key) = self.key {
+            query.push("key", key);
+        }
+
+        let route = Route::GetNews {
+            news: self.news.take(),
+        };
+
+        let req = Request::with_query(route, query);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetNewsPost => NewsPost);
@@ -0,0 +1,134 @@
+use crate::{
+    model::notification_::{Notification, NotificationCategory, Notifications},
+    request::{Body, Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+use futures::future::TryFutureExt;
+
+/// Get the authenticated user's [`Notifications`](crate::model::notification::Notifications).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetNotifications<'a> {
+    fut: Option<Pending<'a, Notifications>>,
+    osu: &'a Osu,
+    max_id: Option<u64>,
+}
+
+impl<'a> GetNotifications<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            max_id: None,
+        }
+    }
+
+    /// Only include notifications whose id is at most the given one
+    /// in order to retrieve older notifications
+    #[inline]
+    pub fn max_id(mut self, max_id: u64) -> Self {
+        self.max_id.replace(max_id);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Notifications> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.notifications.inc();
+
+        let mut query = Query::new();
+
+        if let Some(max_id) = self.max_id {
+            query.push("max_id", max_id);
+        }
+
+        let req = Request::with_query(Route::GetNotifications, query);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetNotifications => Notifications);
+
+/// Mark notifications of the authenticated user as read.
+///
+/// Notifications can be specified individually, by their category, or all at once.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MarkNotificationsRead<'a> {
+    fut: Option<Pending<'a, ()>>,
+    osu: &'a Osu,
+    identities: Vec<Body>,
+    notifications: Vec<Body>,
+}
+
+impl<'a> MarkNotificationsRead<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            identities: Vec::new(),
+            notifications: Vec::new(),
+        }
+    }
+
+    /// Mark the given notification as read
+    #[inline]
+    pub fn notification(mut self, notification: &Notification) -> Self {
+        let mut identity = Body::default();
+        identity.push_without_quotes("id", notification.notification_id);
+
+        if let Some(category) = notification.category_name() {
+            identity.push_with_quotes("category", category);
+        }
+
+        identity.push_escaped("object_type", &notification.object_type);
+        identity.push_without_quotes("object_id", notification.object_id);
+        self.notifications.push(identity);
+
+        self
+    }
+
+    /// Mark all notifications of the given category as read
+    #[inline]
+    pub fn category(mut self, category: NotificationCategory) -> Self {
+        let mut identity = Body::default();
+        identity.push_with_quotes("category", category);
+        self.identities.push(identity);
+
+        self
+    }
+
+    /// Mark all notifications as read
+    #[inline]
+    pub fn all(mut self) -> Self {
+        self.identities.push(Body::default());
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, ()> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.notifications_mark_read.inc();
+
+        let mut body = Body::default();
+
+        if !self.identities.is_empty() {
+            body.push_objects("identities", self.identities.drain(..));
+        }
+
+        if !self.notifications.is_empty() {
+            body.push_objects("notifications", self.notifications.drain(..));
+        }
+
+        let req = Request::with_body(Route::PostNotificationsMarkRead, body);
+
+        let fut = self.osu.request_raw(req).map_ok(|_| ());
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(MarkNotificationsRead => ());
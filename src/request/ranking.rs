@@ -1,16 +1,24 @@
 use crate::{
     model::{
-        ranking_::{ChartRankings, CountryRankings, RankingType, Rankings, Spotlight},
+        ranking_::{
+            ChartRankings, CountryRankings, RankEstimate, RankingType, Rankings, Spotlight,
+        },
         user_::CountryCode,
         GameMode,
     },
     request::{Pending, Query, Request},
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
 use futures::future::TryFutureExt;
 use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
 
 /// Get a [`ChartRankings`](crate::model::ranking::ChartRankings) struct
 /// containing a [`Spotlight`](crate::model::ranking::Spotlight), its
@@ -244,6 +252,133 @@ impl<'a> GetPerformanceRankings<'a> {
 
 poll_req!(GetPerformanceRankings => Rankings);
 
+/// Get the pp of the user at a given rank of the performance leaderboard,
+/// i.e. the amount of pp that must be exceeded in order to reach that rank.
+///
+/// Only a single [`GetPerformanceRankings`] page is requested.
+/// The result is `None` if the rank is not within the leaderboard's pages.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetPpForRank<'a> {
+    fut: Option<Pending<'a, Option<f32>>>,
+    osu: &'a Osu,
+    mode: GameMode,
+    rank: u32,
+    country: Option<CountryCode>,
+    cache: &'a RankingsCache,
+}
+
+impl<'a> GetPpForRank<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mode: GameMode, rank: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mode,
+            rank,
+            country: None,
+            cache: osu.rankings_cache(),
+        }
+    }
+
+    /// Specify a country code to consider the country leaderboard instead.
+    #[inline]
+    pub fn country(mut self, country: impl Into<CountryCode>) -> Self {
+        self.country.replace(country.into());
+
+        self
+    }
+
+    /// Use and fill the given cache for ranking pages
+    /// instead of the client's cache.
+    #[inline]
+    pub fn cache(mut self, cache: &'a RankingsCache) -> Self {
+        self.cache = cache;
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Option<f32>> {
+        let fetcher = PageFetcher {
+            osu: self.osu,
+            mode: self.mode,
+            country: self.country.take(),
+            cache: self.cache,
+        };
+
+        let rank = self.rank;
+
+        let fut = async move { pp_for_rank(rank, |page| fetcher.fetch(page)).await };
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetPpForRank => Option<f32>);
+
+/// Get the [`RankEstimate`](crate::model::ranking::RankEstimate) of a pp value,
+/// i.e. the rank it would have on the performance leaderboard.
+///
+/// [`GetPerformanceRankings`] pages are binary searched so that only
+/// a handful of requests are necessary, at most nine for the global leaderboard.
+/// Pages are reused across estimates through the client's [`RankingsCache`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetRankEstimate<'a> {
+    fut: Option<Pending<'a, RankEstimate>>,
+    osu: &'a Osu,
+    mode: GameMode,
+    pp: f32,
+    country: Option<CountryCode>,
+    cache: &'a RankingsCache,
+}
+
+impl<'a> GetRankEstimate<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mode: GameMode, pp: f32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mode,
+            pp,
+            country: None,
+            cache: osu.rankings_cache(),
+        }
+    }
+
+    /// Specify a country code to estimate the country rank instead.
+    #[inline]
+    pub fn country(mut self, country: impl Into<CountryCode>) -> Self {
+        self.country.replace(country.into());
+
+        self
+    }
+
+    /// Use and fill the given cache for ranking pages
+    /// instead of the client's cache.
+    #[inline]
+    pub fn cache(mut self, cache: &'a RankingsCache) -> Self {
+        self.cache = cache;
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, RankEstimate> {
+        let fetcher = PageFetcher {
+            osu: self.osu,
+            mode: self.mode,
+            country: self.country.take(),
+            cache: self.cache,
+        };
+
+        let pp = self.pp;
+
+        let fut = async move { estimate_rank(pp, |page| fetcher.fetch(page)).await };
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetRankEstimate => RankEstimate);
+
 /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
 /// [`UserCompact`](crate::model::user::UserCompact)s are sorted
 /// by their ranked score, i.e. the current ranked score leaderboard.
@@ -345,3 +480,302 @@ poll_req!(GetSpotlights => Vec<Spotlight>);
 struct Spotlights {
     spotlights: Vec<Spotlight>,
 }
+
+/// The leaderboard consists of at most 200 pages
+const RANKINGS_MAX_PAGE: u32 = 200;
+
+/// Each leaderboard page contains 50 users
+const RANKINGS_PAGE_SIZE: u32 = 50;
+
+/// Caches the pp values of performance leaderboard pages that were requested through
+/// [`GetRankEstimate`] or [`GetPpForRank`] so that repeated estimates don't
+/// request the same pages again.
+///
+/// Since the leaderboard changes continuously, pages expire after a given duration
+/// which defaults to ten minutes.
+#[derive(Debug)]
+pub struct RankingsCache {
+    max_age: Duration,
+    pages: Mutex<HashMap<PageKey, (Instant, RankingsPage)>>,
+}
+
+type PageKey = (GameMode, Option<CountryCode>, u32);
+
+impl RankingsCache {
+    /// Create a new cache whose pages expire after `max_age`.
+    #[inline]
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            pages: Mutex::default(),
+        }
+    }
+
+    /// Remove all cached pages.
+    #[inline]
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn get(&self, key: &PageKey) -> Option<RankingsPage> {
+        let mut pages = self.lock();
+
+        match pages.get(key) {
+            Some((fetched_at, page)) if fetched_at.elapsed() < self.max_age => Some(page.clone()),
+            Some(_) => {
+                pages.remove(key);
+
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: PageKey, page: RankingsPage) {
+        let max_age = self.max_age;
+        let mut pages = self.lock();
+        pages.retain(|_, (fetched_at, _)| fetched_at.elapsed() < max_age);
+        pages.insert(key, (Instant::now(), page));
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<PageKey, (Instant, RankingsPage)>> {
+        self.pages.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for RankingsCache {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Duration::from_secs(600))
+    }
+}
+
+/// The pp values of a leaderboard page in descending order
+#[derive(Clone, Debug)]
+struct RankingsPage {
+    pp: Arc<[f32]>,
+    total: u32,
+}
+
+/// Where a pp value is located relative to a leaderboard page
+#[derive(Debug, PartialEq)]
+enum PagePosition {
+    /// No user of the page has more pp
+    Before,
+    /// The given amount of users on the page have more pp
+    Within(u32),
+    /// All users of the page have more pp
+    After,
+}
+
+impl RankingsPage {
+    fn position(&self, pp: f32) -> PagePosition {
+        match (self.pp.first(), self.pp.last()) {
+            (Some(_), Some(&last)) if last > pp => PagePosition::After,
+            (Some(&first), _) if first > pp => {
+                PagePosition::Within(self.pp.iter().take_while(|&&p| p > pp).count() as u32)
+            }
+            _ => PagePosition::Before,
+        }
+    }
+}
+
+struct PageFetcher<'a> {
+    osu: &'a Osu,
+    mode: GameMode,
+    country: Option<CountryCode>,
+    cache: &'a RankingsCache,
+}
+
+impl PageFetcher<'_> {
+    async fn fetch(&self, page: u32) -> OsuResult<RankingsPage> {
+        let key = (self.mode, self.country.clone(), page);
+
+        if let Some(page) = self.cache.get(&key) {
+            return Ok(page);
+        }
+
+        let mut req = GetPerformanceRankings::new(self.osu, self.mode).page(page);
+
+        if let Some(ref country) = self.country {
+            req = req.country(country.clone());
+        }
+
+        let rankings = req.await?;
+
+        let page = RankingsPage {
+            pp: rankings
+                .ranking
+                .iter()
+                .map(|user| user.statistics.as_ref().map_or(0.0, |stats| stats.pp))
+                .collect(),
+            total: rankings.total,
+        };
+
+        self.cache.insert(key, page.clone());
+
+        Ok(page)
+    }
+}
+
+async fn estimate_rank<F, Fut>(pp: f32, mut fetch: F) -> OsuResult<RankEstimate>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = OsuResult<RankingsPage>>,
+{
+    let first = fetch(1).await?;
+
+    match first.position(pp) {
+        PagePosition::Before => return Ok(RankEstimate::Exact(1)),
+        PagePosition::Within(count) => return Ok(RankEstimate::Exact(count + 1)),
+        PagePosition::After => {}
+    }
+
+    let last_page = first
+        .total
+        .div_ceil(RANKINGS_PAGE_SIZE)
+        .clamp(1, RANKINGS_MAX_PAGE);
+
+    // All users on pages before `lo` have more pp, all users on pages after `hi` have less
+    let mut lo = 2;
+    let mut hi = last_page;
+
+    while lo <= hi {
+        let mid = lo + (hi - lo) / 2;
+
+        match fetch(mid).await?.position(pp) {
+            PagePosition::Before => hi = mid - 1,
+            PagePosition::Within(count) => {
+                return Ok(RankEstimate::Exact(
+                    (mid - 1) * RANKINGS_PAGE_SIZE + count + 1,
+                ))
+            }
+            PagePosition::After => lo = mid + 1,
+        }
+    }
+
+    if lo <= last_page {
+        return Ok(RankEstimate::Exact((lo - 1) * RANKINGS_PAGE_SIZE + 1));
+    }
+
+    let max_entries = RANKINGS_MAX_PAGE * RANKINGS_PAGE_SIZE;
+
+    // Unless the leaderboard was cut off, the pp value is simply last
+    if first.total < max_entries {
+        Ok(RankEstimate::Exact(first.total + 1))
+    } else {
+        Ok(RankEstimate::AtLeast(max_entries + 1))
+    }
+}
+
+async fn pp_for_rank<F, Fut>(rank: u32, mut fetch: F) -> OsuResult<Option<f32>>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = OsuResult<RankingsPage>>,
+{
+    if rank == 0 || rank > RANKINGS_MAX_PAGE * RANKINGS_PAGE_SIZE {
+        return Ok(None);
+    }
+
+    let page = (rank - 1) / RANKINGS_PAGE_SIZE + 1;
+    let idx = ((rank - 1) % RANKINGS_PAGE_SIZE) as usize;
+
+    Ok(fetch(page).await?.pp.get(idx).copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+
+    /// A leaderboard of `total` users where the user at rank `n` has `100_000 - n` pp
+    fn leaderboard(total: u32) -> impl Fn(u32) -> RankingsPage {
+        move |page| {
+            let start = (page - 1) * RANKINGS_PAGE_SIZE + 1;
+            let end = (page * RANKINGS_PAGE_SIZE).min(total);
+
+            RankingsPage {
+                pp: (start..=end).map(|rank| (100_000 - rank) as f32).collect(),
+                total,
+            }
+        }
+    }
+
+    fn estimate(pp: f32, total: u32) -> (RankEstimate, usize) {
+        let pages = leaderboard(total);
+        let requests = Cell::new(0);
+
+        let fetch = |page| {
+            requests.set(requests.get() + 1);
+            let page = pages(page);
+
+            async move { Ok(page) }
+        };
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let estimate = rt.block_on(estimate_rank(pp, fetch)).unwrap();
+
+        (estimate, requests.get())
+    }
+
+    #[test]
+    fn page_position() {
+        let page = RankingsPage {
+            pp: Arc::from(vec![300.0, 200.0, 100.0]),
+            total: 3,
+        };
+
+        assert_eq!(page.position(400.0), PagePosition::Before);
+        assert_eq!(page.position(300.0), PagePosition::Before);
+        assert_eq!(page.position(150.0), PagePosition::Within(2));
+        assert_eq!(page.position(100.0), PagePosition::Within(2));
+        assert_eq!(page.position(50.0), PagePosition::After);
+    }
+
+    #[test]
+    fn estimate_global_rank() {
+        // The user at rank 1 has 99_999 pp
+        assert_eq!(estimate(150_000.0, 10_000), (RankEstimate::Exact(1), 1));
+        assert_eq!(estimate(99_998.5, 10_000).0, RankEstimate::Exact(2));
+
+        for &rank in [50, 51, 1234, 5000, 9999, 10_000].iter() {
+            let pp = (100_000 - rank) as f32 + 0.5;
+            let (estimate, requests) = estimate(pp, 10_000);
+
+            assert_eq!(estimate, RankEstimate::Exact(rank), "rank {}", rank);
+            assert!(requests <= 9, "{} requests for rank {}", requests, rank);
+        }
+
+        assert_eq!(estimate(0.0, 10_000).0, RankEstimate::AtLeast(10_001));
+    }
+
+    #[test]
+    fn estimate_country_rank() {
+        assert_eq!(estimate(99_900.5, 120).0, RankEstimate::Exact(100));
+        assert_eq!(estimate(0.0, 120).0, RankEstimate::Exact(121));
+        assert_eq!(estimate(0.0, 0).0, RankEstimate::Exact(1));
+    }
+
+    #[test]
+    fn pp_for_given_rank() {
+        let pages = leaderboard(120);
+        let fetch = |page| {
+            let page = pages(page);
+
+            async move { Ok(page) }
+        };
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        assert_eq!(rt.block_on(pp_for_rank(1, fetch)).unwrap(), Some(99_999.0));
+        assert_eq!(rt.block_on(pp_for_rank(51, fetch)).unwrap(), Some(99_949.0));
+        assert_eq!(rt.block_on(pp_for_rank(121, fetch)).unwrap(), None);
+        assert_eq!(rt.block_on(pp_for_rank(0, fetch)).unwrap(), None);
+    }
+}
@@ -18,21 +18,33 @@ use crate::{
 pub struct GetReplayRaw<'a> {
     fut: Option<Pending<'a, Vec<u8>>>,
     osu: &'a Osu,
-    mode: GameMode,
+    mode: Option<GameMode>,
     score_id: u64,
 }
 
 impl<'a> GetReplayRaw<'a> {
     #[inline]
-    pub(crate) fn new(osu: &'a Osu, mode: GameMode, score_id: u64) -> Self {
+    pub(crate) fn new(osu: &'a Osu, score_id: u64) -> Self {
         Self {
             fut: None,
             osu,
-            mode,
+            mode: None,
             score_id,
         }
     }
 
+    /// Specify the mode of the score in case the score id is
+    /// a legacy score id of osu!stable for that mode.
+    ///
+    /// Without a specified mode, the score id is considered to be
+    /// an osu!lazer score id.
+    #[inline]
+    pub fn mode(mut self, mode: GameMode) -> Self {
+        self.mode.replace(mode);
+
+        self
+    }
+
     fn start(&mut self) -> Pending<'a, Vec<u8>> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.replay.inc();
@@ -42,7 +54,9 @@ impl<'a> GetReplayRaw<'a> {
             score_id: self.score_id,
         };
 
-        let fut = self.osu.request_raw(Request::new(route)).map_ok(Vec::from);
+        let req = Request::new(route);
+
+        let fut = self.osu.request_raw(req).map_ok(Vec::from);
 
         Box::pin(fut)
     }
@@ -61,13 +75,25 @@ pub struct GetReplay<'a> {
 #[cfg(feature = "replay")]
 impl<'a> GetReplay<'a> {
     #[inline]
-    pub(crate) fn new(osu: &'a Osu, mode: GameMode, score_id: u64) -> Self {
+    pub(crate) fn new(osu: &'a Osu, score_id: u64) -> Self {
         Self {
             fut: None,
-            inner: Some(GetReplayRaw::new(osu, mode, score_id)),
+            inner: Some(GetReplayRaw::new(osu, score_id)),
         }
     }
 
+    /// Specify the mode of the score in case the score id is
+    /// a legacy score id of osu!stable for that mode.
+    ///
+    /// Without a specified mode, the score id is considered to be
+    /// an osu!lazer score id.
+    #[inline]
+    pub fn mode(mut self, mode: GameMode) -> Self {
+        self.inner = self.inner.take().map(|inner| inner.mode(mode));
+
+        self
+    }
+
     fn start(&mut self) -> Pending<'a, Replay> {
         let fut = self.inner.take().unwrap().map(|res| {
             let bytes = res?;
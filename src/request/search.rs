@@ -0,0 +1,96 @@
+use crate::{
+    model::search_::SearchResult,
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+#[cfg(feature = "cache")]
+use futures::TryFutureExt;
+
+/// Search for users and wiki pages in form of a
+/// [`SearchResult`](crate::model::search::SearchResult).
+///
+/// If neither [`users`](GetSearch::users) nor [`wiki_pages`](GetSearch::wiki_pages)
+/// is specified, both will be searched.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetSearch<'a> {
+    fut: Option<Pending<'a, SearchResult>>,
+    osu: &'a Osu,
+    query: Option<String>,
+    mode: &'static str,
+    page: Option<usize>,
+}
+
+impl<'a> GetSearch<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, query: impl Into<String>) -> Self {
+        Self {
+            fut: None,
+            osu,
+            query: Some(query.into()),
+            mode: "all",
+            page: None,
+        }
+    }
+
+    /// Only search for users
+    #[inline]
+    pub fn users(mut self) -> Self {
+        self.mode = "user";
+
+        self
+    }
+
+    /// Only search for wiki pages
+    #[inline]
+    pub fn wiki_pages(mut self) -> Self {
+        self.mode = "wiki_page";
+
+        self
+    }
+
+    /// Get a specific page of the results, starting at 1.
+    ///
+    /// Only applies when searching for either users or wiki pages, not both.
+    #[inline]
+    pub fn page(mut self, page: usize) -> Self {
+        self.page.replace(page);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, SearchResult> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.search.inc();
+
+        let mut query = Query::new();
+
+        query.push("mode", self.mode);
+
+        if let Some(q) = self.query.take() {
+            query.push_encoded("query", &q);
+        }
+
+        if let Some(page) = self.page {
+            query.push("page", page);
+        }
+
+        let req = Request::with_query(Route::GetSearch, query);
+        let osu = self.osu;
+        let fut = osu.request::<SearchResult>(req);
+
+        #[cfg(feature = "cache")]
+        let fut = fut.inspect_ok(move |result| {
+            if let Some(ref users) = result.users {
+                for user in users.users.iter() {
+                    osu.update_cache(user.user_id, &user.username);
+                }
+            }
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetSearch => SearchResult);
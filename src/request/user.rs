@@ -5,7 +5,7 @@ use crate::{
         kudosu_::KudosuHistory,
         recent_event_::RecentEvent,
         score_::Score,
-        user_::{User, UserCompact},
+        user_::{BeatmapsetFavourites, User, UserCompact, Users},
         GameMode,
     },
     prelude::Username,
@@ -14,11 +14,9 @@ use crate::{
     Osu,
 };
 
+use futures::future::TryFutureExt;
 use smallstr::SmallString;
-use std::fmt;
-
-#[cfg(feature = "cache")]
-use {futures::future::TryFutureExt, std::mem};
+use std::{fmt, mem};
 
 /// Either a user id as u32 or a username as String.
 ///
@@ -125,6 +123,41 @@ impl<'a> GetOwnData<'a> {
 
 poll_req!(GetOwnData => User);
 
+/// Get the beatmapset ids of the authenticated user's favourite beatmapsets.
+///
+/// Note that the client has to be initialized through the OAuth process
+/// in order for this endpoint to not return an error.
+///
+/// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetOwnBeatmapsetFavourites<'a> {
+    fut: Option<Pending<'a, Vec<u32>>>,
+    osu: &'a Osu,
+}
+
+impl<'a> GetOwnBeatmapsetFavourites<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self { fut: None, osu }
+    }
+
+    fn start(&mut self) -> Pending<'a, Vec<u32>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.own_beatmapset_favourites.inc();
+
+        let req = Request::new(Route::GetOwnBeatmapsetFavourites);
+
+        let fut = self
+            .osu
+            .request::<BeatmapsetFavourites>(req)
+            .map_ok(|favourites| favourites.beatmapset_ids);
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetOwnBeatmapsetFavourites => Vec<u32>);
+
 /// Get a [`User`](crate::model::user::User) by their id.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetUser<'a> {
@@ -855,6 +888,78 @@ impl<'a> GetUserScores<'a> {
 
 poll_req!(GetUserScores => Vec<Score>);
 
+/// Get a vec of [`UserCompact`](crate::model::user::UserCompact)
+/// by their user id or username.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetUsersLookup<'a> {
+    fut: Option<Pending<'a, Vec<UserCompact>>>,
+    osu: &'a Osu,
+    queries: Vec<Query>,
+}
+
+impl<'a> GetUsersLookup<'a> {
+    /// The API accepts at most 50 users per request
+    const CHUNK_SIZE: usize = 50;
+
+    #[inline]
+    pub(crate) fn new<I, U>(osu: &'a Osu, users: I) -> Self
+    where
+        I: IntoIterator<Item = U>,
+        U: Into<UserId>,
+    {
+        let mut queries = Vec::new();
+
+        for (i, user) in users.into_iter().enumerate() {
+            if i % Self::CHUNK_SIZE == 0 {
+                queries.push(Query::new());
+            }
+
+            let query = queries.last_mut().unwrap();
+
+            match user.into() {
+                UserId::Id(user_id) => query.push("ids[]", user_id),
+                UserId::Name(name) => query.push("ids[]", format_args!("@{name}")),
+            }
+        }
+
+        Self {
+            fut: None,
+            osu,
+            queries,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, Vec<UserCompact>> {
+        let osu = self.osu;
+        let queries = mem::take(&mut self.queries);
+
+        let fut = async move {
+            let mut users = Vec::new();
+
+            for query in queries {
+                #[cfg(feature = "metrics")]
+                osu.metrics.users_lookup.inc();
+
+                let req = Request::with_query(Route::GetUsersLookup, query);
+                let chunk: Users = osu.request(req).await?;
+
+                #[cfg(feature = "cache")]
+                for user in chunk.users.iter() {
+                    osu.update_cache(user.user_id, &user.username);
+                }
+
+                users.extend(chunk.users);
+            }
+
+            Ok(users)
+        };
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetUsersLookup => Vec<UserCompact>);
+
 /// Get a vec of [`UserCompact`](crate::model::user::UserCompact) by their ids.
 #[allow(dead_code)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -32,7 +32,13 @@ pub(crate) enum Route {
     GetBeatmapsetFromMapId,
     GetBeatmapsetEvents,
     GetBeatmapsetSearch,
+    GetChangelog,
+    GetChangelogBuild {
+        stream: String,
+        version: String,
+    },
     GetComments,
+    GetEvents,
     GetForumPosts {
         topic_id: u64,
     },
@@ -40,8 +46,10 @@ pub(crate) enum Route {
         match_id: Option<u32>,
     },
     GetNews {
-        news: Option<()>,
+        news: Option<String>,
     },
+    GetNotifications,
+    GetOwnBeatmapsetFavourites,
     GetOwnData {
         mode: Option<GameMode>,
     },
@@ -53,13 +61,14 @@ pub(crate) enum Route {
         user_id: u32,
     },
     GetReplay {
-        mode: GameMode,
+        mode: Option<GameMode>,
         score_id: u64,
     },
     GetScore {
-        mode: GameMode,
+        mode: Option<GameMode>,
         score_id: u64,
     },
+    GetSearch,
     GetSeasonalBackgrounds,
     GetSpotlights,
     GetUser {
@@ -79,10 +88,28 @@ pub(crate) enum Route {
     },
     #[allow(dead_code)]
     GetUsers,
+    GetUsersLookup,
     GetWikiPage {
         locale: String,
         page: Option<String>,
     },
+    DeleteComment {
+        comment_id: u32,
+    },
+    DeleteCommentVote {
+        comment_id: u32,
+    },
+    PostBeatmapsetFavourite {
+        mapset_id: u32,
+    },
+    PostComment,
+    PostCommentVote {
+        comment_id: u32,
+    },
+    PostNotificationsMarkRead,
+    PutComment {
+        comment_id: u32,
+    },
 }
 
 impl Route {
@@ -111,7 +138,13 @@ impl Route {
             Self::GetBeatmapsetFromMapId => (Method::GET, "beatmapsets/lookup".into()),
             Self::GetBeatmapsetEvents => (Method::GET, "beatmapsets/events".into()),
             Self::GetBeatmapsetSearch => (Method::GET, "beatmapsets/search".into()),
+            Self::GetChangelog => (Method::GET, "changelog".into()),
+            Self::GetChangelogBuild { stream, version } => (
+                Method::GET,
+                format!("changelog/{}/{}", stream, version).into(),
+            ),
             Self::GetComments => (Method::GET, "comments".into()),
+            Self::GetEvents => (Method::GET, "events".into()),
             Self::GetForumPosts { topic_id } => {
                 (Method::GET, format!("forums/topics/{}", topic_id).into())
             }
@@ -125,12 +158,14 @@ impl Route {
             }
             Self::GetNews { news } => {
                 let path = match news {
-                    Some(_news) => unimplemented!(),
+                    Some(news) => format!("news/{}", news).into(),
                     None => "news".into(),
                 };
 
                 (Method::GET, path)
             }
+            Self::GetNotifications => (Method::GET, "notifications".into()),
+            Self::GetOwnBeatmapsetFavourites => (Method::GET, "me/beatmapset-favourites".into()),
             Self::GetOwnData { mode } => {
                 let path = match mode {
                     Some(mode) => format!("me/{}", mode).into(),
@@ -147,13 +182,23 @@ impl Route {
                 Method::GET,
                 format!("users/{}/recent_activity", user_id).into(),
             ),
-            Self::GetReplay { mode, score_id } => (
-                Method::GET,
-                format!("scores/{}/{}/download", mode, score_id).into(),
-            ),
+            Self::GetReplay { mode, score_id } => {
+                let path = match mode {
+                    Some(mode) => format!("scores/{}/{}/download", mode, score_id),
+                    None => format!("scores/{}/download", score_id),
+                };
+
+                (Method::GET, path.into())
+            }
             Self::GetScore { mode, score_id } => {
-                (Method::GET, format!("scores/{}/{}", mode, score_id).into())
+                let path = match mode {
+                    Some(mode) => format!("scores/{}/{}", mode, score_id),
+                    None => format!("scores/{}", score_id),
+                };
+
+                (Method::GET, path.into())
             }
+            Self::GetSearch => (Method::GET, "search".into()),
             Self::GetSeasonalBackgrounds => (Method::GET, "seasonal-backgrounds".into()),
             Self::GetSpotlights => (Method::GET, "spotlights".into()),
             Self::GetUser { user_id, mode } => {
@@ -180,6 +225,7 @@ impl Route {
                 format!("users/{}/scores/{}", user_id, score_type).into(),
             ),
             Self::GetUsers => (Method::GET, "users".into()),
+            Self::GetUsersLookup => (Method::GET, "users/lookup".into()),
             Self::GetWikiPage { locale, page } => {
                 let mut path = format!("wiki/{}/", locale);
 
@@ -189,6 +235,25 @@ impl Route {
 
                 (Method::GET, path.into())
             }
+            Self::DeleteComment { comment_id } => {
+                (Method::DELETE, format!("comments/{}", comment_id).into())
+            }
+            Self::DeleteCommentVote { comment_id } => (
+                Method::DELETE,
+                format!("comments/{}/vote", comment_id).into(),
+            ),
+            Self::PostBeatmapsetFavourite { mapset_id } => (
+                Method::POST,
+                format!("beatmapsets/{}/favourites", mapset_id).into(),
+            ),
+            Self::PostComment => (Method::POST, "comments".into()),
+            Self::PostCommentVote { comment_id } => {
+                (Method::POST, format!("comments/{}/vote", comment_id).into())
+            }
+            Self::PostNotificationsMarkRead => (Method::POST, "notifications/mark-read".into()),
+            Self::PutComment { comment_id } => {
+                (Method::PUT, format!("comments/{}", comment_id).into())
+            }
         }
     }
 }
@@ -0,0 +1,52 @@
+use crate::model::{
+    beatmap::{BeatmapCompact, RankStatus},
+    matches::{MatchGame, MatchScore, ScoringType, Team, TeamType},
+    GameMode, GameMods,
+};
+
+use time::OffsetDateTime;
+
+/// A passed score without any hit results
+pub(crate) fn score(user_id: u32, team: Team, score: u32, mods: GameMods) -> MatchScore {
+    MatchScore {
+        accuracy: 100.0,
+        max_combo: 0,
+        mods,
+        pass: true,
+        perfect: false,
+        score,
+        slot: 0,
+        statistics: Default::default(),
+        team,
+        user_id,
+    }
+}
+
+/// A finished ScoreV2 game on a ranked osu!standard map whose id is also the game id
+pub(crate) fn game(map_id: u32, scores: Vec<MatchScore>) -> MatchGame {
+    let map = BeatmapCompact {
+        checksum: None,
+        creator_id: 1,
+        fail_times: None,
+        map_id,
+        mapset: None,
+        max_combo: None,
+        mode: GameMode::Osu,
+        seconds_total: 100,
+        stars: 5.0,
+        status: RankStatus::Ranked,
+        version: String::new(),
+    };
+
+    MatchGame {
+        game_id: map_id as u64,
+        start_time: OffsetDateTime::UNIX_EPOCH,
+        end_time: Some(OffsetDateTime::UNIX_EPOCH),
+        mode: GameMode::Osu,
+        scoring_type: ScoringType::ScoreV2,
+        team_type: TeamType::TeamVS,
+        mods: GameMods::NoFail,
+        map: Some(map),
+        scores,
+    }
+}
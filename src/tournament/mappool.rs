@@ -0,0 +1,161 @@
+use crate::model::GameMods;
+
+use std::iter::FromIterator;
+
+/// Mods that are commonly allowed on top of a slot's mods and thus ignored when validating picks
+const IGNORED_MODS: GameMods = GameMods::from_bits_truncate(
+    GameMods::NoFail.bits() | GameMods::SuddenDeath.bits() | GameMods::Perfect.bits(),
+);
+
+/// A slot of a [`Mappool`] such as NM1, HD2, or TB.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappoolSlot {
+    /// The slot's name e.g. `"DT1"`
+    pub name: String,
+    pub map_id: u32,
+    /// The mods required to play the slot; irrelevant for free mod slots
+    pub mods: GameMods,
+    /// Whether players may choose their own mods
+    pub free_mod: bool,
+    /// Whether the slot is the tiebreaker
+    pub tiebreaker: bool,
+}
+
+impl MappoolSlot {
+    /// Create a new slot and infer its mods from the name's prefix.
+    ///
+    /// Recognized prefixes are `NM`, `HD`, `HR`, `DT`, `NC`, `EZ`, `FL`, `HT`,
+    /// `FM` for free mod, and `TB` for the tiebreaker which is considered free mod as well.
+    /// For other names, the slot requires no mods; use [`MappoolSlot::mods`] to specify them.
+    pub fn new(name: impl Into<String>, map_id: u32) -> Self {
+        let name = name.into();
+        let prefix = name.get(..2).unwrap_or_default().to_ascii_uppercase();
+
+        let mut slot = Self {
+            name,
+            map_id,
+            mods: GameMods::NoMod,
+            free_mod: false,
+            tiebreaker: false,
+        };
+
+        match prefix.as_str() {
+            "FM" => slot.free_mod = true,
+            "TB" => {
+                slot.free_mod = true;
+                slot.tiebreaker = true;
+            }
+            "HD" => slot.mods = GameMods::Hidden,
+            "HR" => slot.mods = GameMods::HardRock,
+            "DT" | "NC" => slot.mods = GameMods::DoubleTime,
+            "EZ" => slot.mods = GameMods::Easy,
+            "FL" => slot.mods = GameMods::Flashlight,
+            "HT" => slot.mods = GameMods::HalfTime,
+            _ => {}
+        }
+
+        slot
+    }
+
+    /// Specify the required mods.
+    #[inline]
+    pub fn mods(mut self, mods: GameMods) -> Self {
+        self.mods = mods;
+        self.free_mod = false;
+
+        self
+    }
+
+    /// Specify whether players may choose their own mods.
+    #[inline]
+    pub fn free_mod(mut self, free_mod: bool) -> Self {
+        self.free_mod = free_mod;
+
+        self
+    }
+
+    /// Check whether the given mods are allowed for this slot.
+    ///
+    /// NF, SD, and PF are always allowed and NC is considered as DT.
+    pub fn allows(&self, mods: GameMods) -> bool {
+        self.free_mod || normalize(mods) == normalize(self.mods)
+    }
+}
+
+fn normalize(mods: GameMods) -> GameMods {
+    let mods = mods.normalize() - IGNORED_MODS;
+
+    if mods.contains(GameMods::NightCore) {
+        (mods - GameMods::NightCore) | GameMods::DoubleTime
+    } else {
+        mods
+    }
+}
+
+/// A collection of [`MappoolSlot`]s.
+///
+/// # Example
+/// ```
+/// use rosu_v2::{model::GameMods, tournament::{Mappool, MappoolSlot}};
+///
+/// let mappool = Mappool::new()
+///     .slot(MappoolSlot::new("NM1", 1_000))
+///     .slot(MappoolSlot::new("HD1", 2_000))
+///     .slot(MappoolSlot::new("DT1", 3_000))
+///     .slot(MappoolSlot::new("FM1", 4_000))
+///     .slot(MappoolSlot::new("TB", 5_000));
+///
+/// let slot = mappool.get(3_000).unwrap();
+/// assert!(slot.allows(GameMods::NightCore | GameMods::NoFail));
+/// assert!(!slot.allows(GameMods::Hidden));
+/// assert!(mappool.tiebreaker().is_some());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Mappool {
+    pub slots: Vec<MappoolSlot>,
+}
+
+impl Mappool {
+    /// Create an empty mappool.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a slot to the mappool.
+    #[inline]
+    pub fn slot(mut self, slot: MappoolSlot) -> Self {
+        self.slots.push(slot);
+
+        self
+    }
+
+    /// Get the slot of the given map id.
+    #[inline]
+    pub fn get(&self, map_id: u32) -> Option<&MappoolSlot> {
+        self.slots.iter().find(|slot| slot.map_id == map_id)
+    }
+
+    /// Get the slot with the given name, case-insensitive.
+    #[inline]
+    pub fn get_by_name(&self, name: &str) -> Option<&MappoolSlot> {
+        self.slots
+            .iter()
+            .find(|slot| slot.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Get the tiebreaker slot.
+    #[inline]
+    pub fn tiebreaker(&self) -> Option<&MappoolSlot> {
+        self.slots.iter().find(|slot| slot.tiebreaker)
+    }
+}
+
+impl FromIterator<MappoolSlot> for Mappool {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = MappoolSlot>>(iter: I) -> Self {
+        Self {
+            slots: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use super::{GameKind, MatchResult};
+use crate::model::matches::{MatchGame, OsuMatch, Team};
+
+use std::collections::HashMap;
+
+/// A player's performance rating over one or more matches, see [`MatchCosts`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchCost {
+    pub user_id: u32,
+    /// The team the player played the most games for, `None` in head-to-head lobbies
+    pub team: Option<Team>,
+    /// The final rating
+    pub cost: f64,
+    /// The average of the player's scores relative to each map's median, including the tiebreaker bonus
+    pub average: f64,
+    /// The amount of games the player submitted a score in
+    pub games_played: usize,
+    /// The amount of games considered for the rating
+    pub games_total: usize,
+}
+
+/// Calculate a "match cost" rating for each player based on their scores relative
+/// to each map's median score.
+///
+/// For every game, a player's score is divided by the median of all scores in that game
+/// and multiplied by `1 + tiebreaker_bonus` if the game was a tiebreaker.
+/// The average of these values is then weighted by the player's participation
+/// i.e. `(games_played / games_total) ^ participation_exponent` so that players
+/// who subbed in for only a few maps are rated fairly but not favored.
+///
+/// Games without scores, unfinished games, and games whose median is zero are ignored.
+///
+/// # Example
+/// ```no_run
+/// use rosu_v2::{tournament::MatchCosts, Osu};
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let osu_match = osu.osu_match_full(123_456_789).await?;
+///
+/// let costs = MatchCosts::new()
+///     .tiebreaker(2_345_678)
+///     .calculate(&osu_match);
+///
+/// for cost in costs {
+///     println!("{}: {:.2}", cost.user_id, cost.cost);
+/// }
+/// # Ok::<_, rosu_v2::error::OsuError>(()) };
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchCosts {
+    participation_exponent: f64,
+    tiebreaker_bonus: f64,
+    tiebreakers: Vec<u32>,
+}
+
+impl Default for MatchCosts {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MatchCosts {
+    /// Create a new calculation with a participation exponent of `1/3` and a tiebreaker bonus of `0.2`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            participation_exponent: 1.0 / 3.0,
+            tiebreaker_bonus: 0.2,
+            tiebreakers: Vec::new(),
+        }
+    }
+
+    /// Specify how strongly participation is weighted, `0.0` disables the weighting.
+    #[inline]
+    pub fn participation_exponent(mut self, exponent: f64) -> Self {
+        self.participation_exponent = exponent.max(0.0);
+
+        self
+    }
+
+    /// Specify the relative bonus for scores on tiebreakers.
+    #[inline]
+    pub fn tiebreaker_bonus(mut self, bonus: f64) -> Self {
+        self.tiebreaker_bonus = bonus;
+
+        self
+    }
+
+    /// Specify the map id of a tiebreaker.
+    ///
+    /// Can be called multiple times e.g. for a series of matches with different tiebreakers.
+    #[inline]
+    pub fn tiebreaker(mut self, map_id: u32) -> Self {
+        self.tiebreakers.push(map_id);
+
+        self
+    }
+
+    /// Calculate the ratings of all players of the match, ordered from best to worst.
+    #[inline]
+    pub fn calculate(&self, osu_match: &OsuMatch) -> Vec<MatchCost> {
+        self.calculate_series(std::slice::from_ref(osu_match))
+    }
+
+    /// Calculate the ratings of all players over a series of matches, ordered from best to worst.
+    pub fn calculate_series(&self, matches: &[OsuMatch]) -> Vec<MatchCost> {
+        let games = matches.iter().flat_map(OsuMatch::games).map(|game| {
+            let tiebreaker = game
+                .map
+                .as_ref()
+                .is_some_and(|map| self.tiebreakers.contains(&map.map_id));
+
+            (game, tiebreaker)
+        });
+
+        self.calculate_games(games)
+    }
+
+    /// Calculate the ratings based on an analyzed tournament match, ordered from best to worst.
+    ///
+    /// Only games that count towards the series score are considered and the tiebreaker
+    /// is determined through the mappool.
+    pub fn calculate_result(&self, result: &MatchResult<'_>) -> Vec<MatchCost> {
+        let games = result
+            .games
+            .iter()
+            .filter(|game| game.kind == GameKind::Pick)
+            .map(|game| {
+                let tiebreaker = game.slot.as_ref().is_some_and(|slot| slot.tiebreaker);
+
+                (game.game, tiebreaker)
+            });
+
+        self.calculate_games(games)
+    }
+
+    /// Calculate the ratings based on the given games and whether they are a tiebreaker,
+    /// ordered from best to worst.
+    pub fn calculate_games<'m, I>(&self, games: I) -> Vec<MatchCost>
+    where
+        I: IntoIterator<Item = (&'m MatchGame, bool)>,
+    {
+        let mut players: HashMap<u32, PlayerStats> = HashMap::new();
+        let mut games_total = 0;
+
+        for (game, tiebreaker) in games {
+            if game.end_time.is_none() {
+                continue;
+            }
+
+            let median = match median(game) {
+                Some(median) if median > 0.0 => median,
+                _ => continue,
+            };
+
+            games_total += 1;
+
+            let multiplier = if tiebreaker {
+                1.0 + self.tiebreaker_bonus
+            } else {
+                1.0
+            };
+
+            for score in game.scores.iter() {
+                let stats = players.entry(score.user_id).or_default();
+                stats.points += score.score as f64 / median * multiplier;
+                stats.games += 1;
+
+                if let Team::Blue | Team::Red = score.team {
+                    *stats.teams.entry(score.team).or_default() += 1;
+                }
+            }
+        }
+
+        let mut costs: Vec<_> = players
+            .into_iter()
+            .map(|(user_id, stats)| {
+                let average = stats.points / stats.games as f64;
+                let participation = stats.games as f64 / games_total as f64;
+
+                MatchCost {
+                    user_id,
+                    team: stats.main_team(),
+                    cost: average * participation.powf(self.participation_exponent),
+                    average,
+                    games_played: stats.games,
+                    games_total,
+                }
+            })
+            .collect();
+
+        costs.sort_by(|a, b| b.cost.total_cmp(&a.cost).then(a.user_id.cmp(&b.user_id)));
+
+        costs
+    }
+}
+
+#[derive(Default)]
+struct PlayerStats {
+    points: f64,
+    games: usize,
+    teams: HashMap<Team, usize>,
+}
+
+impl PlayerStats {
+    fn main_team(&self) -> Option<Team> {
+        self.teams
+            .iter()
+            .max_by_key(|(team, count)| (**count, **team == Team::Red))
+            .map(|(team, _)| *team)
+    }
+}
+
+fn median(game: &MatchGame) -> Option<f64> {
+    let mut scores: Vec<_> = game.scores.iter().map(|score| score.score).collect();
+
+    if scores.is_empty() {
+        return None;
+    }
+
+    scores.sort_unstable();
+    let mid = scores.len() / 2;
+
+    let median = if scores.len() % 2 == 0 {
+        (scores[mid - 1] as f64 + scores[mid] as f64) / 2.0
+    } else {
+        scores[mid] as f64
+    };
+
+    Some(median)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{model::GameMods, tournament::fixtures};
+
+    fn game(scores: &[(u32, Team, u32)]) -> MatchGame {
+        let scores = scores
+            .iter()
+            .map(|&(user_id, team, score)| fixtures::score(user_id, team, score, GameMods::NoMod))
+            .collect();
+
+        fixtures::game(1, scores)
+    }
+
+    #[test]
+    fn match_costs_with_sub() {
+        let games = [
+            game(&[
+                (1, Team::Red, 400),
+                (2, Team::Blue, 200),
+                (3, Team::Blue, 300),
+            ]),
+            game(&[
+                (1, Team::Red, 300),
+                (2, Team::Blue, 300),
+                (3, Team::Blue, 300),
+            ]),
+            // player 4 subs in for player 3
+            game(&[
+                (1, Team::Red, 100),
+                (2, Team::Blue, 200),
+                (4, Team::Blue, 600),
+            ]),
+        ];
+
+        let costs = MatchCosts::new()
+            .participation_exponent(1.0)
+            .calculate_games(games.iter().map(|game| (game, false)));
+
+        assert_eq!(costs.len(), 4);
+
+        let sub = costs.iter().find(|cost| cost.user_id == 4).unwrap();
+        assert_eq!(sub.games_played, 1);
+        assert_eq!(sub.games_total, 3);
+        assert_eq!(sub.team, Some(Team::Blue));
+        assert!((sub.average - 3.0).abs() < 1e-9);
+        assert!((sub.cost - 1.0).abs() < 1e-9);
+
+        let order: Vec<_> = costs.iter().map(|cost| cost.user_id).collect();
+        assert_eq!(order, [4, 1, 2, 3]);
+
+        let first = &costs[1];
+        assert!((first.average - (4.0 / 3.0 + 1.0 + 0.5) / 3.0).abs() < 1e-9);
+
+        let tiebreaker = MatchCosts::new()
+            .calculate_games(games.iter().enumerate().map(|(i, game)| (game, i == 2)));
+
+        let sub_tb = tiebreaker.iter().find(|cost| cost.user_id == 4).unwrap();
+        assert!((sub_tb.average - 3.6).abs() < 1e-9);
+    }
+}
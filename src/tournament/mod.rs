@@ -0,0 +1,470 @@
+mod mappool;
+mod match_cost;
+mod qualifiers;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+pub use mappool::{Mappool, MappoolSlot};
+pub use match_cost::{MatchCost, MatchCosts};
+pub use qualifiers::{
+    Entrant, QualifierEntry, QualifierMapResult, QualifierResults, Qualifiers, ScoreAggregation,
+    SeedingMethod, TieBreak,
+};
+
+use crate::model::{
+    matches::{MatchGame, MatchScore, OsuMatch, ScoringType, Team, TeamType},
+    GameMods,
+};
+
+/// The format of a tournament match.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TournamentFormat {
+    /// The maximum amount of counted maps e.g. 7 for best-of-7
+    pub best_of: u32,
+    pub team_type: TeamType,
+    pub scoring_type: ScoringType,
+    /// The amount of games at the start of a match that are considered warmups
+    pub warmups: usize,
+}
+
+impl TournamentFormat {
+    /// Create a new format for a best-of-N team vs match with ScoreV2 and no warmups.
+    #[inline]
+    pub fn new(best_of: u32) -> Self {
+        Self {
+            best_of,
+            team_type: TeamType::TeamVS,
+            scoring_type: ScoringType::ScoreV2,
+            warmups: 0,
+        }
+    }
+
+    /// Specify the team type, e.g. `TeamType::HeadToHead` for 1v1 matches.
+    #[inline]
+    pub fn team_type(mut self, team_type: TeamType) -> Self {
+        self.team_type = team_type;
+
+        self
+    }
+
+    /// Specify the scoring type.
+    #[inline]
+    pub fn scoring_type(mut self, scoring_type: ScoringType) -> Self {
+        self.scoring_type = scoring_type;
+
+        self
+    }
+
+    /// Specify the amount of warmups at the start of a match.
+    #[inline]
+    pub fn warmups(mut self, warmups: usize) -> Self {
+        self.warmups = warmups;
+
+        self
+    }
+
+    /// The amount of won maps required to win the match.
+    #[inline]
+    pub fn wins_required(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    fn is_team_vs(&self) -> bool {
+        matches!(self.team_type, TeamType::TeamVS | TeamType::TagTeamVS)
+    }
+}
+
+/// A side of a tournament match, either a team or a single player.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MatchSide {
+    Team(Team),
+    Player(u32),
+}
+
+/// How a game of a tournament match was classified.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GameKind {
+    /// The game counts towards the series score
+    Pick,
+    /// The game is a warmup, either due to the format or because
+    /// it was played on an off-pool map before the first pick
+    Warmup,
+    /// The game was aborted i.e. it did not finish or nobody submitted a score
+    Aborted,
+    /// The game is the last game of the match and still in progress
+    InProgress,
+    /// The game was played on an off-pool map after the first pick
+    OffPool,
+    /// The game was played after the match was already decided
+    AfterMatch,
+}
+
+/// An irregularity of a tournament game that referees should look into.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PickIssue {
+    /// The map is not part of the mappool
+    OffPool,
+    /// A player used mods that are not allowed for the slot
+    WrongMods {
+        user_id: u32,
+        expected: GameMods,
+        actual: GameMods,
+    },
+    /// The lobby's team type does not match the format
+    WrongTeamType(TeamType),
+    /// The lobby's scoring type does not match the format
+    WrongScoringType(ScoringType),
+    /// The tiebreaker was played before the series score was tied at match point
+    EarlyTiebreaker,
+}
+
+/// The analysis of a single game of a tournament match, see [`Tournament::analyze`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameAnalysis<'m> {
+    pub game: &'m MatchGame,
+    /// The mappool slot of the game's map
+    pub slot: Option<MappoolSlot>,
+    pub kind: GameKind,
+    pub issues: Vec<PickIssue>,
+    /// The total of each side on this map according to the format's scoring type,
+    /// ordered from best to worst
+    pub totals: Vec<(MatchSide, f64)>,
+    /// The side that won the map, `None` on ties or if the game was not counted
+    pub winner: Option<MatchSide>,
+    /// The series score after this game, ordered from most to least points
+    pub series: Vec<(MatchSide, u32)>,
+}
+
+/// The result of a tournament match, see [`Tournament::analyze`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchResult<'m> {
+    pub games: Vec<GameAnalysis<'m>>,
+    /// The final series score, ordered from most to least points
+    pub series: Vec<(MatchSide, u32)>,
+    /// The side that won the match, `None` if the match is not decided yet
+    pub winner: Option<MatchSide>,
+}
+
+impl MatchResult<'_> {
+    /// Iterate over all games that count towards the series score.
+    #[inline]
+    pub fn picks(&self) -> impl Iterator<Item = &GameAnalysis<'_>> {
+        self.games.iter().filter(|game| game.kind == GameKind::Pick)
+    }
+
+    /// Iterate over all games that have issues.
+    #[inline]
+    pub fn flagged(&self) -> impl Iterator<Item = &GameAnalysis<'_>> {
+        self.games.iter().filter(|game| !game.issues.is_empty())
+    }
+}
+
+/// A mappool and a format to analyze tournament matches with.
+///
+/// # Example
+/// ```no_run
+/// use rosu_v2::{
+///     tournament::{Mappool, MappoolSlot, Tournament, TournamentFormat},
+///     Osu,
+/// };
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let mappool: Mappool = vec![
+///     MappoolSlot::new("NM1", 1_000),
+///     MappoolSlot::new("HD1", 2_000),
+///     MappoolSlot::new("TB", 3_000),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let tournament = Tournament::new(mappool, TournamentFormat::new(7).warmups(2));
+///
+/// let osu_match = osu.osu_match_full(123_456_789).await?;
+/// let result = tournament.analyze(&osu_match);
+///
+/// for game in result.flagged() {
+///     println!("Game {} has issues: {:?}", game.game.game_id, game.issues);
+/// }
+///
+/// println!("Final score: {:?}, winner: {:?}", result.series, result.winner);
+/// # Ok::<_, rosu_v2::error::OsuError>(()) };
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tournament {
+    pub mappool: Mappool,
+    pub format: TournamentFormat,
+}
+
+impl Tournament {
+    #[inline]
+    pub fn new(mappool: Mappool, format: TournamentFormat) -> Self {
+        Self { mappool, format }
+    }
+
+    /// Analyze all games of the match.
+    ///
+    /// Note that the match should contain all events, see
+    /// [`Osu::osu_match_full`](crate::Osu::osu_match_full).
+    #[inline]
+    pub fn analyze<'m>(&self, osu_match: &'m OsuMatch) -> MatchResult<'m> {
+        self.analyze_games(osu_match.games())
+    }
+
+    /// Analyze the given games, ordered by their start, e.g. the games of
+    /// multiple lobbies if a match had to be recreated.
+    pub fn analyze_games<'m, I>(&self, games: I) -> MatchResult<'m>
+    where
+        I: IntoIterator<Item = &'m MatchGame>,
+    {
+        let games: Vec<_> = games.into_iter().collect();
+        let last_idx = games.len().saturating_sub(1);
+
+        let mut series: Vec<(MatchSide, u32)> = Vec::new();
+        let mut winner = None;
+        let mut picked_any = false;
+
+        let mut analyses = Vec::with_capacity(games.len());
+
+        for (i, game) in games.into_iter().enumerate() {
+            let slot = game
+                .map
+                .as_ref()
+                .and_then(|map| self.mappool.get(map.map_id))
+                .cloned();
+
+            let kind = if i < self.format.warmups || (slot.is_none() && !picked_any) {
+                GameKind::Warmup
+            } else if game.end_time.is_none() && i == last_idx {
+                GameKind::InProgress
+            } else if game.end_time.is_none() || game.scores.is_empty() {
+                GameKind::Aborted
+            } else if slot.is_none() {
+                GameKind::OffPool
+            } else if winner.is_some() {
+                GameKind::AfterMatch
+            } else {
+                GameKind::Pick
+            };
+
+            let issues = match kind {
+                GameKind::Warmup | GameKind::Aborted => Vec::new(),
+                _ => self.issues(game, slot.as_ref(), &series),
+            };
+
+            let totals = self.totals(game);
+
+            let map_winner = match (kind, totals.as_slice()) {
+                (GameKind::Pick, [(side, first), rest @ ..]) => match rest.first() {
+                    Some((_, second)) if second >= first => None,
+                    _ => Some(*side),
+                },
+                _ => None,
+            };
+
+            if kind == GameKind::Pick {
+                picked_any = true;
+
+                for (side, _) in totals.iter() {
+                    if !series.iter().any(|(s, _)| s == side) {
+                        series.push((*side, 0));
+                    }
+                }
+
+                if let Some(map_winner) = map_winner {
+                    if let Some((_, points)) = series.iter_mut().find(|(s, _)| *s == map_winner) {
+                        *points += 1;
+
+                        if *points >= self.format.wins_required() {
+                            winner = Some(map_winner);
+                        }
+                    }
+                }
+
+                series.sort_by(|(_, a), (_, b)| b.cmp(a));
+            }
+
+            analyses.push(GameAnalysis {
+                game,
+                slot,
+                kind,
+                issues,
+                totals,
+                winner: map_winner,
+                series: series.clone(),
+            });
+        }
+
+        MatchResult {
+            games: analyses,
+            series,
+            winner,
+        }
+    }
+
+    fn issues(
+        &self,
+        game: &MatchGame,
+        slot: Option<&MappoolSlot>,
+        series: &[(MatchSide, u32)],
+    ) -> Vec<PickIssue> {
+        let mut issues = Vec::new();
+
+        if game.team_type != self.format.team_type {
+            issues.push(PickIssue::WrongTeamType(game.team_type));
+        }
+
+        if game.scoring_type != self.format.scoring_type {
+            issues.push(PickIssue::WrongScoringType(game.scoring_type));
+        }
+
+        let slot = match slot {
+            Some(slot) => slot,
+            None => {
+                issues.push(PickIssue::OffPool);
+
+                return issues;
+            }
+        };
+
+        for score in game.scores.iter() {
+            let mods = game.mods | score.mods;
+
+            if !slot.allows(mods) {
+                issues.push(PickIssue::WrongMods {
+                    user_id: score.user_id,
+                    expected: slot.mods,
+                    actual: mods,
+                });
+            }
+        }
+
+        if slot.tiebreaker {
+            let match_point = self.format.wins_required().saturating_sub(1);
+            let tied = series.len() >= 2 && series.iter().all(|(_, points)| *points == match_point);
+
+            if !tied {
+                issues.push(PickIssue::EarlyTiebreaker);
+            }
+        }
+
+        issues
+    }
+
+    /// Calculate the total of each side, ordered from best to worst.
+    fn totals(&self, game: &MatchGame) -> Vec<(MatchSide, f64)> {
+        let mut totals: Vec<(MatchSide, f64, usize)> = Vec::new();
+
+        for score in game.scores.iter() {
+            let side = match (self.format.is_team_vs(), score.team) {
+                (true, team @ (Team::Blue | Team::Red)) => MatchSide::Team(team),
+                _ => MatchSide::Player(score.user_id),
+            };
+
+            let value = self.score_value(score);
+
+            match totals.iter_mut().find(|(s, ..)| *s == side) {
+                Some((_, total, count)) => {
+                    *total += value;
+                    *count += 1;
+                }
+                None => totals.push((side, value, 1)),
+            }
+        }
+
+        let mut totals: Vec<_> = totals
+            .into_iter()
+            .map(|(side, total, count)| match self.format.scoring_type {
+                // Accuracy is averaged, everything else is summed up
+                ScoringType::Accuracy => (side, total / count as f64),
+                _ => (side, total),
+            })
+            .collect();
+
+        totals.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        totals
+    }
+
+    fn score_value(&self, score: &MatchScore) -> f64 {
+        match self.format.scoring_type {
+            ScoringType::Score | ScoringType::ScoreV2 => score.score as f64,
+            ScoringType::Accuracy => score.accuracy as f64,
+            ScoringType::Combo => score.max_combo as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        fixtures::{self, game},
+        *,
+    };
+
+    #[test]
+    fn analyze_series() {
+        let mappool: Mappool = vec![
+            MappoolSlot::new("NM1", 1),
+            MappoolSlot::new("HD1", 2),
+            MappoolSlot::new("TB", 3),
+        ]
+        .into_iter()
+        .collect();
+
+        let tournament = Tournament::new(mappool, TournamentFormat::new(3));
+
+        let red = |score, mods| fixtures::score(10, Team::Red, score, mods);
+        let blue = |score, mods| fixtures::score(20, Team::Blue, score, mods);
+
+        let games = vec![
+            // warmup on an off-pool map
+            game(99, vec![red(1, GameMods::NoMod), blue(2, GameMods::NoMod)]),
+            game(
+                1,
+                vec![red(500, GameMods::NoMod), blue(400, GameMods::NoMod)],
+            ),
+            game(
+                2,
+                vec![red(300, GameMods::Hidden), blue(600, GameMods::HardRock)],
+            ),
+            // aborted
+            game(3, Vec::new()),
+            game(
+                3,
+                vec![red(700, GameMods::NoMod), blue(100, GameMods::Hidden)],
+            ),
+        ];
+
+        let result = tournament.analyze_games(&games);
+
+        let kinds: Vec<_> = result.games.iter().map(|game| game.kind).collect();
+
+        assert_eq!(
+            kinds,
+            [
+                GameKind::Warmup,
+                GameKind::Pick,
+                GameKind::Pick,
+                GameKind::Aborted,
+                GameKind::Pick
+            ]
+        );
+
+        assert!(matches!(
+            result.games[2].issues.as_slice(),
+            [PickIssue::WrongMods { user_id: 20, .. }]
+        ));
+        assert!(result.games[4].issues.is_empty());
+        assert_eq!(result.games[2].series[0].1, 1);
+        assert_eq!(result.winner, Some(MatchSide::Team(Team::Red)));
+        assert_eq!(
+            result.series,
+            [
+                (MatchSide::Team(Team::Red), 2),
+                (MatchSide::Team(Team::Blue), 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,508 @@
+use super::Mappool;
+use crate::{
+    model::matches::{MatchGame, OsuMatch},
+    Osu, OsuResult,
+};
+
+use std::{cmp::Ordering, collections::HashMap};
+
+/// How multiple runs of an entrant on the same map are combined.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScoreAggregation {
+    /// Use the best run
+    Best,
+    /// Use the average of all runs
+    Average,
+}
+
+/// How entrants are seeded based on their per-map scores.
+///
+/// Entrants that did not play a map are considered to have a score of zero on it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeedingMethod {
+    /// Sum of the raw scores
+    TotalScore,
+    /// Sum of the per-map z-scores i.e. the amount of standard deviations above the map's mean
+    ZScore,
+    /// Average of the per-map percentile ranks i.e. the percentage of entrants that scored lower
+    PercentileRank,
+    /// Average of the per-map placements, lower is better
+    AverageRank,
+}
+
+/// How entrants with the same seeding value are ordered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Prefer the higher sum of raw scores
+    TotalScore,
+    /// Prefer the better best placement, then the better second best placement, and so on
+    BestPlacement,
+    /// Tied entrants share the same seed
+    Shared,
+}
+
+/// An entrant of a qualifier stage, either a single player or a team.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Entrant {
+    Player(u32),
+    Team(String),
+}
+
+/// The result of an entrant on a single map of the mappool.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QualifierMapResult {
+    /// Name of the mappool slot
+    pub slot: String,
+    pub map_id: u32,
+    /// The aggregated score, `None` if the entrant did not play the map
+    pub score: Option<f64>,
+    /// The amount of runs on the map
+    pub runs: usize,
+    /// The placement on the map, starting at 1
+    pub rank: usize,
+    /// The map's contribution to the seeding value
+    pub value: f64,
+}
+
+/// An entrant's entry in the [`QualifierResults`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct QualifierEntry {
+    /// The seed, starting at 1
+    pub seed: usize,
+    pub entrant: Entrant,
+    /// The value the entrant was seeded by
+    pub value: f64,
+    /// The sum of all aggregated scores
+    pub total_score: f64,
+    /// Results for each map in the order of the mappool's slots
+    pub maps: Vec<QualifierMapResult>,
+}
+
+/// The rankings of a qualifier stage, see [`Qualifiers`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QualifierResults {
+    /// All entrants ordered by their seed
+    pub rankings: Vec<QualifierEntry>,
+}
+
+impl QualifierResults {
+    /// Get the entry of the given entrant.
+    #[inline]
+    pub fn get(&self, entrant: &Entrant) -> Option<&QualifierEntry> {
+        self.rankings.iter().find(|entry| entry.entrant == *entrant)
+    }
+}
+
+/// Aggregate the scores of qualifier lobbies on a mappool and seed the entrants.
+///
+/// Only finished games on maps of the mappool are considered and scores
+/// with mods that are not allowed for the map's slot are ignored.
+///
+/// # Example
+/// ```no_run
+/// use rosu_v2::{
+///     tournament::{Mappool, MappoolSlot, Qualifiers, SeedingMethod},
+///     Osu,
+/// };
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let mappool: Mappool = vec![
+///     MappoolSlot::new("NM1", 1_000),
+///     MappoolSlot::new("HD1", 2_000),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let results = Qualifiers::new(mappool)
+///     .seeding(SeedingMethod::ZScore)
+///     .team("Team A", [2, 3])
+///     .team("Team B", [4, 5])
+///     .fetch(&osu, &[111, 222, 333])
+///     .await?;
+///
+/// for entry in results.rankings {
+///     println!("#{} {:?}: {:.3}", entry.seed, entry.entrant, entry.value);
+/// }
+/// # Ok::<_, rosu_v2::error::OsuError>(()) };
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Qualifiers {
+    mappool: Mappool,
+    aggregation: ScoreAggregation,
+    seeding: SeedingMethod,
+    tie_break: TieBreak,
+    /// Maps user ids to team names
+    teams: HashMap<u32, String>,
+}
+
+impl Qualifiers {
+    /// Create new qualifiers for the given mappool.
+    ///
+    /// Defaults to the best run per map, seeding by total score, and breaking ties by placements.
+    #[inline]
+    pub fn new(mappool: Mappool) -> Self {
+        Self {
+            mappool,
+            aggregation: ScoreAggregation::Best,
+            seeding: SeedingMethod::TotalScore,
+            tie_break: TieBreak::BestPlacement,
+            teams: HashMap::new(),
+        }
+    }
+
+    /// Specify how multiple runs on the same map are combined.
+    #[inline]
+    pub fn aggregation(mut self, aggregation: ScoreAggregation) -> Self {
+        self.aggregation = aggregation;
+
+        self
+    }
+
+    /// Specify how the entrants are seeded.
+    #[inline]
+    pub fn seeding(mut self, seeding: SeedingMethod) -> Self {
+        self.seeding = seeding;
+
+        self
+    }
+
+    /// Specify how ties are broken.
+    #[inline]
+    pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+
+        self
+    }
+
+    /// Add a team with the given members.
+    ///
+    /// The scores of a team's members within the same game are summed up to a single run.
+    /// As soon as a team is specified, scores of players without a team are ignored.
+    pub fn team<I>(mut self, name: impl Into<String>, user_ids: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        let name = name.into();
+
+        for user_id in user_ids {
+            self.teams.insert(user_id, name.clone());
+        }
+
+        self
+    }
+
+    /// Request all matches including all of their events and aggregate their games.
+    pub async fn fetch(&self, osu: &Osu, match_ids: &[u32]) -> OsuResult<QualifierResults> {
+        let mut matches = Vec::with_capacity(match_ids.len());
+
+        for &match_id in match_ids {
+            matches.push(osu.osu_match_full(match_id).await?);
+        }
+
+        Ok(self.aggregate(&matches))
+    }
+
+    /// Aggregate the games of all given matches.
+    #[inline]
+    pub fn aggregate(&self, matches: &[OsuMatch]) -> QualifierResults {
+        self.aggregate_games(matches.iter().flat_map(OsuMatch::games))
+    }
+
+    /// Aggregate the given games.
+    pub fn aggregate_games<'m, I>(&self, games: I) -> QualifierResults
+    where
+        I: IntoIterator<Item = &'m MatchGame>,
+    {
+        let n_maps = self.mappool.slots.len();
+        let mut entrants: Vec<Entrant> = Vec::new();
+
+        // runs[entrant][map]
+        let mut runs: Vec<Vec<Vec<f64>>> = Vec::new();
+
+        for game in games {
+            let map_idx = match self.map_idx(game) {
+                Some(idx) => idx,
+                None => continue,
+            };
+
+            for (entrant, score) in self.game_runs(game, map_idx) {
+                let entrant_idx = match entrants.iter().position(|e| *e == entrant) {
+                    Some(idx) => idx,
+                    None => {
+                        entrants.push(entrant);
+                        runs.push(vec![Vec::new(); n_maps]);
+
+                        entrants.len() - 1
+                    }
+                };
+
+                runs[entrant_idx][map_idx].push(score);
+            }
+        }
+
+        let scores: Vec<Vec<Option<f64>>> = runs
+            .iter()
+            .map(|maps| maps.iter().map(|runs| self.aggregate_runs(runs)).collect())
+            .collect();
+
+        let mut entries: Vec<_> = entrants
+            .into_iter()
+            .enumerate()
+            .map(|(i, entrant)| QualifierEntry {
+                seed: 0,
+                entrant,
+                value: 0.0,
+                total_score: scores[i].iter().flatten().sum(),
+                maps: Vec::with_capacity(n_maps),
+            })
+            .collect();
+
+        for (map_idx, slot) in self.mappool.slots.iter().enumerate() {
+            let values: Vec<f64> = scores
+                .iter()
+                .map(|maps| maps[map_idx].unwrap_or(0.0))
+                .collect();
+
+            let stats = MapStats::new(&values);
+
+            for (i, entry) in entries.iter_mut().enumerate() {
+                let score = values[i];
+                let rank = values.iter().filter(|&&other| other > score).count() + 1;
+
+                let value = match self.seeding {
+                    SeedingMethod::TotalScore => score,
+                    SeedingMethod::ZScore => stats.z_score(score),
+                    SeedingMethod::PercentileRank => stats.percentile(&values, score),
+                    SeedingMethod::AverageRank => rank as f64,
+                };
+
+                entry.maps.push(QualifierMapResult {
+                    slot: slot.name.clone(),
+                    map_id: slot.map_id,
+                    score: scores[i][map_idx],
+                    runs: runs[i][map_idx].len(),
+                    rank,
+                    value,
+                });
+            }
+        }
+
+        for entry in entries.iter_mut() {
+            let sum: f64 = entry.maps.iter().map(|map| map.value).sum();
+
+            entry.value = match self.seeding {
+                SeedingMethod::TotalScore | SeedingMethod::ZScore => sum,
+                SeedingMethod::PercentileRank | SeedingMethod::AverageRank => {
+                    sum / n_maps.max(1) as f64
+                }
+            };
+        }
+
+        entries.sort_by(|a, b| self.compare(a, b));
+        self.assign_seeds(&mut entries);
+
+        QualifierResults { rankings: entries }
+    }
+
+    fn map_idx(&self, game: &MatchGame) -> Option<usize> {
+        // Unfinished games are either in progress or aborted
+        game.end_time?;
+        let map_id = game.map.as_ref()?.map_id;
+
+        self.mappool
+            .slots
+            .iter()
+            .position(|slot| slot.map_id == map_id)
+    }
+
+    /// Collect the runs of a single game, combining team members' scores.
+    fn game_runs(&self, game: &MatchGame, map_idx: usize) -> Vec<(Entrant, f64)> {
+        let slot = &self.mappool.slots[map_idx];
+        let mut runs: Vec<(Entrant, f64)> = Vec::new();
+
+        let scores = game
+            .scores
+            .iter()
+            .filter(|score| slot.allows(game.mods | score.mods));
+
+        for score in scores {
+            let entrant = if self.teams.is_empty() {
+                Entrant::Player(score.user_id)
+            } else {
+                match self.teams.get(&score.user_id) {
+                    Some(team) => Entrant::Team(team.clone()),
+                    None => continue,
+                }
+            };
+
+            match runs.iter_mut().find(|(e, _)| *e == entrant) {
+                Some((_, total)) => *total += score.score as f64,
+                None => runs.push((entrant, score.score as f64)),
+            }
+        }
+
+        runs
+    }
+
+    fn aggregate_runs(&self, runs: &[f64]) -> Option<f64> {
+        if runs.is_empty() {
+            return None;
+        }
+
+        let aggregated = match self.aggregation {
+            ScoreAggregation::Best => runs.iter().copied().fold(f64::MIN, f64::max),
+            ScoreAggregation::Average => runs.iter().sum::<f64>() / runs.len() as f64,
+        };
+
+        Some(aggregated)
+    }
+
+    fn compare(&self, a: &QualifierEntry, b: &QualifierEntry) -> Ordering {
+        let by_value = match self.seeding {
+            SeedingMethod::AverageRank => a.value.total_cmp(&b.value),
+            _ => b.value.total_cmp(&a.value),
+        };
+
+        by_value.then_with(|| match self.tie_break {
+            TieBreak::TotalScore => b.total_score.total_cmp(&a.total_score),
+            TieBreak::BestPlacement => placements(a).cmp(&placements(b)),
+            TieBreak::Shared => Ordering::Equal,
+        })
+    }
+
+    fn assign_seeds(&self, entries: &mut [QualifierEntry]) {
+        for i in 0..entries.len() {
+            let shared = i > 0 && self.compare(&entries[i - 1], &entries[i]) == Ordering::Equal;
+
+            entries[i].seed = if shared { entries[i - 1].seed } else { i + 1 };
+        }
+    }
+}
+
+/// Placements on all maps ordered from best to worst
+fn placements(entry: &QualifierEntry) -> Vec<usize> {
+    let mut ranks: Vec<_> = entry.maps.iter().map(|map| map.rank).collect();
+    ranks.sort_unstable();
+
+    ranks
+}
+
+struct MapStats {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl MapStats {
+    fn new(values: &[f64]) -> Self {
+        let n = values.len().max(1) as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+        }
+    }
+
+    fn z_score(&self, value: f64) -> f64 {
+        if self.std_dev > 0.0 {
+            (value - self.mean) / self.std_dev
+        } else {
+            0.0
+        }
+    }
+
+    fn percentile(&self, values: &[f64], value: f64) -> f64 {
+        if values.len() <= 1 {
+            return 100.0;
+        }
+
+        let below = values.iter().filter(|&&other| other < value).count();
+
+        below as f64 / (values.len() - 1) as f64 * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{matches::Team, GameMods},
+        tournament::{fixtures, MappoolSlot},
+    };
+
+    fn game(map_id: u32, scores: &[(u32, u32)]) -> MatchGame {
+        let scores = scores
+            .iter()
+            .map(|&(user_id, score)| fixtures::score(user_id, Team::None, score, GameMods::NoMod))
+            .collect();
+
+        fixtures::game(map_id, scores)
+    }
+
+    fn mappool() -> Mappool {
+        vec![MappoolSlot::new("NM1", 1), MappoolSlot::new("NM2", 2)]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn seed_players() {
+        let games = vec![
+            game(1, &[(10, 500), (20, 400), (30, 300)]),
+            game(1, &[(20, 600)]),
+            game(2, &[(10, 100), (20, 200), (30, 900)]),
+            // off-pool
+            game(3, &[(30, 1_000_000)]),
+        ];
+
+        let results = Qualifiers::new(mappool()).aggregate_games(&games);
+        let order: Vec<_> = results.rankings.iter().map(|e| e.entrant.clone()).collect();
+
+        assert_eq!(
+            order,
+            [
+                Entrant::Player(30),
+                Entrant::Player(20),
+                Entrant::Player(10)
+            ]
+        );
+
+        let second = &results.rankings[1];
+        assert_eq!(second.total_score, 800.0);
+        assert_eq!(second.maps[0].runs, 2);
+        assert_eq!(second.maps[0].rank, 1);
+
+        let averaged = Qualifiers::new(mappool())
+            .aggregation(ScoreAggregation::Average)
+            .seeding(SeedingMethod::AverageRank)
+            .aggregate_games(&games);
+
+        assert_eq!(
+            averaged.get(&Entrant::Player(20)).unwrap().maps[0].score,
+            Some(500.0)
+        );
+        assert_eq!(averaged.rankings[0].entrant, Entrant::Player(20));
+    }
+
+    #[test]
+    fn seed_teams_with_shared_ties() {
+        let games = vec![
+            game(1, &[(1, 100), (2, 100), (3, 150), (4, 50)]),
+            game(2, &[(1, 300), (3, 300), (5, 1000)]),
+        ];
+
+        let results = Qualifiers::new(mappool())
+            .team("A", [1, 2])
+            .team("B", [3, 4])
+            .seeding(SeedingMethod::ZScore)
+            .tie_break(TieBreak::Shared)
+            .aggregate_games(&games);
+
+        assert_eq!(results.rankings.len(), 2);
+        assert_eq!(results.rankings[0].seed, 1);
+        assert_eq!(results.rankings[1].seed, 1);
+        assert!(results.rankings[0].value.abs() < f64::EPSILON);
+    }
+}
@@ -13,6 +13,7 @@ use rosu_v2::{
         beatmap::{BeatmapsetSearchSort, RankStatus},
         GameMode,
     },
+    request::UserId,
     Osu,
 };
 use tokio::sync::{Mutex, MutexGuard};
@@ -226,6 +227,36 @@ async fn beatmapset_search() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn changelog() -> Result<()> {
+    let changelog = OSU.get().await?.changelog().stream("lazer").await?;
+
+    println!(
+        "Received {} builds and {} streams",
+        changelog.builds.len(),
+        changelog.streams.len(),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn changelog_build() -> Result<()> {
+    let build = OSU
+        .get()
+        .await?
+        .changelog_build("stable40", "20230613.1")
+        .await?;
+
+    println!(
+        "Received build {} with {} entries",
+        build.display_version,
+        build.changelog_entries.map_or(0, |entries| entries.len()),
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn comments() -> Result<()> {
     let bundle = OSU.get().await?.comments().sort_new().await?;
@@ -264,6 +295,19 @@ async fn country_rankings() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn events() -> Result<()> {
+    let osu = OSU.get().await?;
+    let events = osu.events().await?;
+    println!("Received {} events", events.events.len());
+
+    if let Some(next) = events.get_next(&osu).await {
+        println!("Received {} more events", next?.events.len());
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn forum_posts() -> Result<()> {
     let posts = OSU
@@ -302,7 +346,8 @@ async fn replay() -> Result<()> {
     let replay = OSU
         .get()
         .await?
-        .replay(GameMode::Osu, COOKIEZI_FREEDOM_DIVE)
+        .replay(COOKIEZI_FREEDOM_DIVE)
+        .mode(GameMode::Osu)
         .await?;
 
     println!("Received replay with the following score: {}", replay.score);
@@ -328,6 +373,23 @@ async fn news() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn news_post() -> Result<()> {
+    let post = OSU
+        .get()
+        .await?
+        .news_post("2023-06-27-new-featured-artists")
+        .await?;
+
+    println!(
+        "Received news post \"{}\" with {} bytes of content",
+        post.title,
+        post.content.map_or(0, |content| content.len()),
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn osu_match() -> Result<()> {
     let osu_match = OSU.get().await?.osu_match(DE_VS_CA).await?;
@@ -340,6 +402,24 @@ async fn osu_match() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn osu_match_full() -> Result<()> {
+    let osu_match = OSU
+        .get()
+        .await?
+        .osu_match_full(DE_VS_CA)
+        .max_pages(5)
+        .await?;
+
+    println!(
+        "Received full match, got {} events and {} users",
+        osu_match.events.len(),
+        osu_match.users.len()
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn osu_matches() -> Result<()> {
     let osu_matches = OSU.get().await?.osu_matches().await?;
@@ -361,6 +441,15 @@ async fn own_data() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore = "requires OAuth to not throw an error"]
+async fn own_beatmapset_favourites() -> Result<()> {
+    let mapset_ids = OSU.get().await?.own_beatmapset_favourites().await?;
+    println!("Received {} favourite mapset ids", mapset_ids.len());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn performance_rankings() -> Result<()> {
     let rankings = OSU
@@ -384,7 +473,8 @@ async fn score() -> Result<()> {
     let score = OSU
         .get()
         .await?
-        .score(COOKIEZI_FREEDOM_DIVE, GameMode::Osu)
+        .score(COOKIEZI_FREEDOM_DIVE)
+        .mode(GameMode::Osu)
         .await?;
 
     println!(
@@ -408,6 +498,20 @@ async fn score_rankings() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn search() -> Result<()> {
+    let result = OSU.get().await?.search("badewanne").users().await?;
+    let users = result.users.unwrap();
+
+    println!(
+        "Received {} out of {} users",
+        users.users.len(),
+        users.total
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn seasonal_backgrounds() -> Result<()> {
     let backgrounds = OSU.get().await?.seasonal_backgrounds().await?;
@@ -511,6 +615,23 @@ async fn users() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn users_lookup() -> Result<()> {
+    let users = OSU
+        .get()
+        .await?
+        .users_lookup([
+            UserId::Id(BADEWANNE3),
+            UserId::Id(SYLAS),
+            UserId::from("freddie benson"),
+        ])
+        .await?;
+
+    println!("Received {} users", users.len());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn wiki() -> Result<()> {
     let page = OSU
@@ -494,6 +494,12 @@ mod types {
                 count_100: 3,
                 count_50: 4,
                 count_miss: 5,
+                large_tick_miss: 0,
+                slider_tail_hit: 0,
+                small_bonus: 0,
+                large_bonus: 0,
+                ignore_hit: 0,
+                ignore_miss: 0,
             },
             slot: 0,
             team: Team::Red,
@@ -501,6 +507,86 @@ mod types {
         }
     }
 
+    pub(super) fn get_news_post() -> NewsPost {
+        let post = |post_id, navigation| NewsPost {
+            post_id,
+            author: "peppy".into(),
+            content: Some("<p>content</p>".to_owned()),
+            edit_url: "https://github.com/ppy/osu-wiki".to_owned(),
+            first_image: "https://assets.ppy.sh/image.jpg".to_owned(),
+            navigation,
+            published_at: get_date(),
+            updated_at: Some(get_date()),
+            slug: "2023-06-27-news".to_owned(),
+            title: "News".to_owned(),
+            preview: Some("preview".to_owned()),
+        };
+
+        let navigation = NewsNavigation {
+            newer: Some(Box::new(post(1, None))),
+            older: None,
+        };
+
+        post(2, Some(navigation))
+    }
+
+    pub(super) fn get_notifications() -> Notifications {
+        let notification = |notification_id, object_type: &str, kind| Notification {
+            notification_id,
+            created_at: get_date(),
+            is_read: false,
+            object_id: 123,
+            object_type: object_type.to_owned(),
+            source_user_id: Some(2),
+            kind,
+        };
+
+        let notifications = vec![
+            notification(
+                3,
+                "beatmapset",
+                NotificationKind::BeatmapsetDiscussion {
+                    event: BeatmapsetDiscussionEvent::PostNew,
+                    details: BeatmapsetDiscussionDetails {
+                        title: "title".to_owned(),
+                        title_unicode: None,
+                        cover_url: Some("https://assets.ppy.sh/cover.jpg".to_owned()),
+                        beatmap_id: Some(456),
+                        discussion_id: Some(789),
+                        post_id: Some(1011),
+                        content: Some("content".to_owned()),
+                        username: Some("peppy".into()),
+                    },
+                },
+            ),
+            notification(
+                2,
+                "news_post",
+                NotificationKind::CommentReply(CommentNotificationDetails {
+                    comment_id: 42,
+                    title: "News".to_owned(),
+                    content: Some("\"reply\"".to_owned()),
+                    cover_url: None,
+                    username: Some("peppy".into()),
+                }),
+            ),
+            notification(
+                1,
+                "user",
+                NotificationKind::Other {
+                    name: "user_beatmapset_new".to_owned(),
+                },
+            ),
+        ];
+
+        Notifications {
+            has_more: true,
+            notifications,
+            unread_count: 3,
+            notification_endpoint: "wss://notify.ppy.sh".to_owned(),
+        }
+    }
+
     pub(super) fn get_score() -> Score {
         Score {
             accuracy: 98.76,
@@ -511,8 +597,12 @@ mod types {
             max_combo: 1234,
             map: Some(get_map()),
             mapset: Some(get_mapset_compact()),
+            build_id: Some(7123),
+            id: Some(2_345_678_901),
+            legacy_score_id: Some(123_456_789_000),
+            legacy_total_score: Some(12_345_678),
             mode: GameMode::Catch,
-            mods: GameMods::Hidden | GameMods::DoubleTime,
+            mods: "HDDT(1.3x)DA(approach_rate=9.5)MU".parse().unwrap(),
             perfect: false,
             pp: Some(456.78),
             rank_country: Some(1),
@@ -527,7 +617,14 @@ mod types {
                 count_100: 300,
                 count_50: 200,
                 count_miss: 1,
+                large_tick_miss: 0,
+                slider_tail_hit: 0,
+                small_bonus: 0,
+                large_bonus: 0,
+                ignore_hit: 0,
+                ignore_miss: 0,
             },
+            maximum_statistics: None,
             user: Some(get_user_compact()),
             user_id: 2,
             weight: Some(ScoreWeight {
@@ -831,6 +928,91 @@ mod types {
     }
 }
 
+mod lazer_format {
+    use rosu_v2::prelude::*;
+
+    #[test]
+    fn deserialize_lazer_score() {
+        let json = r#"{
+            "classic_total_score": 1234567,
+            "preserve": true,
+            "processed": true,
+            "ranked": true,
+            "maximum_statistics": {
+                "great": 1024,
+                "ignore_hit": 345,
+                "large_tick_hit": 12,
+                "slider_tail_hit": 333,
+                "large_bonus": 4
+            },
+            "mods": [{"acronym": "HD"}, {"acronym": "DT", "settings": {"speed_change": 1.3}}],
+            "statistics": {
+                "ok": 11,
+                "meh": 1,
+                "miss": 2,
+                "great": 1010,
+                "ignore_hit": 340,
+                "ignore_miss": 5,
+                "large_tick_hit": 11,
+                "large_tick_miss": 1,
+                "slider_tail_hit": 330,
+                "large_bonus": 4
+            },
+            "total_score_without_mods": 912345,
+            "beatmap_id": 1234,
+            "best_id": 4567890123,
+            "id": 2345678901,
+            "rank": "A",
+            "type": "solo_score",
+            "user_id": 2,
+            "accuracy": 0.98765,
+            "build_id": 7123,
+            "ended_at": "2024-05-01T12:34:56Z",
+            "has_replay": true,
+            "is_perfect_combo": false,
+            "legacy_perfect": false,
+            "legacy_score_id": 4567890123,
+            "legacy_total_score": 12345678,
+            "max_combo": 1400,
+            "passed": true,
+            "pp": 345.67,
+            "ruleset_id": 0,
+            "started_at": "2024-05-01T12:30:00Z",
+            "total_score": 1012345,
+            "current_user_attributes": {"pin": null}
+        }"#;
+
+        let score: Score = serde_json::from_str(json).unwrap();
+
+        assert_eq!(score.id, Some(2_345_678_901));
+        assert_eq!(score.score_id, Some(4_567_890_123));
+        assert_eq!(score.legacy_score_id, Some(4_567_890_123));
+        assert_eq!(score.legacy_total_score, Some(12_345_678));
+        assert_eq!(score.build_id, Some(7123));
+        assert_eq!(score.mode, GameMode::Osu);
+        assert_eq!(score.score, 1_012_345);
+        assert_eq!(score.replay, Some(true));
+        assert!(!score.perfect);
+        assert!((score.accuracy - 98.765).abs() < 0.001);
+        assert_eq!(score.mods.to_string(), "HDDT(1.3x)");
+
+        let statistics = &score.statistics;
+        assert_eq!(statistics.count_300, 1010);
+        assert_eq!(statistics.count_100, 11);
+        assert_eq!(statistics.count_50, 1);
+        assert_eq!(statistics.count_miss, 2);
+        assert_eq!(statistics.large_tick_miss, 1);
+        assert_eq!(statistics.slider_tail_hit, 330);
+        assert_eq!(statistics.large_bonus, 4);
+        assert_eq!(statistics.ignore_hit, 340);
+        assert_eq!(statistics.ignore_miss, 5);
+
+        let maximum = score.maximum_statistics.unwrap();
+        assert_eq!(maximum.count_300, 1024);
+        assert_eq!(maximum.slider_tail_hit, 333);
+    }
+}
+
 #[cfg(feature = "serialize")]
 mod serde_tests {
     use serde::{de::DeserializeOwned, Serialize};
@@ -885,6 +1067,16 @@ mod serde_tests {
         roundtrip(&get_match());
     }
 
+    #[test]
+    fn serde_news_post() {
+        roundtrip(&get_news_post());
+    }
+
+    #[test]
+    fn serde_notifications() {
+        roundtrip(&get_notifications());
+    }
+
     #[test]
     fn serde_score() {
         roundtrip(&get_score());
@@ -961,6 +1153,16 @@ mod rkyv_tests {
         roundtrip(&get_match());
     }
 
+    #[test]
+    fn serde_news_post() {
+        roundtrip(&get_news_post());
+    }
+
+    #[test]
+    fn serde_notifications() {
+        roundtrip(&get_notifications());
+    }
+
     #[test]
     fn serde_score() {
         roundtrip(&get_score());